@@ -232,6 +232,7 @@ defmodule Explorer.Backend.DataFrame do
             ) :: df
   @callback put(df, out_df :: df(), column_name(), series()) :: df
   @callback nil_count(df) :: df()
+  @callback describe(df, percentiles :: [float()]) :: df()
   @callback explode(df, out_df :: df(), columns :: [column_name()]) :: df()
   @callback unnest(df, out_df :: df(), columns :: [column_name()]) :: df()
   @callback correlation(df, out_df :: df(), method :: atom()) :: df()
@@ -253,6 +254,7 @@ defmodule Explorer.Backend.DataFrame do
 
   @callback summarise_with(df, out_df :: df(), aggregations :: [{column_name(), lazy_series()}]) ::
               df
+  @callback map_groups(df, fun :: (df() -> df())) :: df
 
   # SQL
 
@@ -279,6 +281,15 @@ defmodule Explorer.Backend.DataFrame do
 
   @doc """
   Default inspect implementation for backends.
+
+  ## Options
+
+    * `:elide_columns` - when true, the values of each column are not shown.
+
+    * `:glimpse` - a function that receives the number of values to show
+      and returns a `{name, dtype, values}` tuple for each column. It lets
+      backends fetch every column at once, instead of one at a time.
+
   """
   def inspect(df, backend, n_rows, inspect_opts, opts \\ [])
       when is_binary(backend) and (is_integer(n_rows) or is_nil(n_rows)) and
@@ -288,7 +299,15 @@ defmodule Explorer.Backend.DataFrame do
     open = A.color("[", :list, inspect_opts)
     close = A.color("]", :list, inspect_opts)
 
-    cols_algebra = build_cols_algebra(df, inspect_opts, elide_columns?)
+    cols_algebra =
+      case Keyword.fetch(opts, :glimpse) do
+        {:ok, glimpse} when not elide_columns? ->
+          build_glimpse_algebra(glimpse.(inspect_opts.limit + 1), inspect_opts)
+
+        _ ->
+          build_cols_algebra(df, inspect_opts, elide_columns?)
+      end
+
     df_info = "#{n_rows || "???"} x #{length(cols_algebra)}"
 
     A.concat([
@@ -337,15 +356,28 @@ defmodule Explorer.Backend.DataFrame do
         |> Series.dtype()
         |> Explorer.Shared.dtype_to_string()
 
-      A.concat([
-        A.line(),
-        A.color("#{name} ", :map, inspect_opts),
-        A.color("#{type} ", :atom, inspect_opts),
-        data
-      ])
+      column_algebra(name, type, data, inspect_opts)
+    end
+  end
+
+  defp build_glimpse_algebra(columns, inspect_opts) do
+    for {name, dtype, values} <- columns do
+      type = Explorer.Shared.dtype_to_string(dtype)
+      data = Explorer.Shared.to_doc(values, inspect_opts)
+
+      column_algebra(name, type, data, inspect_opts)
     end
   end
 
+  defp column_algebra(name, type, data, inspect_opts) do
+    A.concat([
+      A.line(),
+      A.color("#{name} ", :map, inspect_opts),
+      A.color("#{type} ", :atom, inspect_opts),
+      data
+    ])
+  end
+
   defp groups_algebra([_ | _] = groups, opts),
     do:
       A.concat([
@@ -5858,6 +5858,38 @@ defmodule Explorer.DataFrame do
     Shared.apply_dataframe(df, :summarise_with, [out_df, column_pairs])
   end
 
+  @doc """
+  Applies `fun` to each group and concatenates the results.
+
+  `fun` receives each group as an ungrouped dataframe, in the order the
+  groups first appear, and must return a dataframe. All returned dataframes
+  must have the same columns in the same order. The result is ungrouped.
+
+  This is meant for per-group logic that can't be expressed with
+  `mutate/2` or `summarise/2`, which should be preferred when possible.
+
+  ## Examples
+
+      iex> df = Explorer.DataFrame.new(a: ["x", "y", "x"], b: [1, 2, 3])
+      iex> grouped = Explorer.DataFrame.group_by(df, "a")
+      iex> Explorer.DataFrame.map_groups(grouped, &Explorer.DataFrame.tail(&1, 1))
+      #Explorer.DataFrame<
+        Polars[2 x 2]
+        a string ["x", "y"]
+        b s64 [3, 2]
+      >
+  """
+  @doc type: :single
+  @spec map_groups(df :: DataFrame.t(), fun :: (DataFrame.t() -> DataFrame.t())) ::
+          DataFrame.t()
+  def map_groups(%DataFrame{groups: []}, _fun) do
+    raise ArgumentError, "map_groups/2 expects a grouped dataframe"
+  end
+
+  def map_groups(%DataFrame{} = df, fun) when is_function(fun, 1) do
+    Shared.apply_dataframe(df, :map_groups, [fun])
+  end
+
   defp names_with_dtypes_for_column_pairs(df, column_pairs) do
     groups = for group <- df.groups, do: {group, df.dtypes[group]}
 
@@ -6319,51 +6351,8 @@ defmodule Explorer.DataFrame do
       raise ArgumentError, "cannot describe a DataFrame without any columns"
     end
 
-    stat_cols = df.names
     percentiles = process_percentiles(opts[:percentiles])
-    numeric_types = Shared.numeric_types()
-    naive_datetime_types = Shared.naive_datetime_types()
-    duration_types = Shared.duration_types()
-
-    metrics_df =
-      summarise_with(df, fn x ->
-        Enum.flat_map(stat_cols, fn c ->
-          dt = x[c].dtype
-          numeric? = dt in numeric_types
-          min_max? = numeric? or dt in naive_datetime_types or dt in duration_types
-
-          [
-            {"count:#{c}", Series.count(x[c])},
-            {"nil_count:#{c}", Series.nil_count(x[c])},
-            {"mean:#{c}", if(numeric?, do: Series.mean(x[c]))},
-            {"std:#{c}", if(numeric?, do: Series.standard_deviation(x[c]))},
-            {"min:#{c}", if(min_max?, do: Series.min(x[c]))}
-          ] ++
-            for p <- percentiles do
-              {"#{trunc(p * 100)}%:#{c}", if(numeric?, do: Series.quantile(x[c], p))}
-            end ++
-            [{"max:#{c}", if(min_max?, do: Series.max(x[c]))}]
-        end)
-      end)
-
-    [metrics_row] = metrics_df |> collect() |> to_rows()
-
-    metrics =
-      ["count", "nil_count", "mean", "std", "min"] ++
-        for(p <- percentiles, do: "#{trunc(p * 100)}%") ++ ["max"]
-
-    data =
-      metrics_df.names
-      |> Enum.chunk_every(length(metrics))
-      |> Enum.zip_with(stat_cols, fn metrics, col ->
-        if df.dtypes[col] in numeric_types do
-          {col, Enum.map(metrics, &metrics_row[&1])}
-        else
-          {col, Enum.map(metrics, &(metrics_row[&1] && "#{metrics_row[&1]}"))}
-        end
-      end)
-
-    new([{"describe", metrics} | data])
+    Shared.apply_dataframe(df, :describe, [percentiles])
   end
 
   defp process_percentiles(nil), do: [0.25, 0.50, 0.75]
@@ -6375,7 +6364,7 @@ defmodule Explorer.DataFrame do
       end
     end)
 
-    Enum.sort(percentiles)
+    percentiles |> Enum.map(&(&1 / 1.0)) |> Enum.sort()
   end
 
   @doc """
@@ -1,5 +1,162 @@
 defmodule Explorer.PolarsBackend do
   @moduledoc """
   The Explorer backend for Polars.
+
+  ## Threads
+
+  Polars runs its parallel work on a thread pool that is started the
+  first time it is needed, with one thread per core by default. To use
+  fewer threads, so Polars does not compete with the BEAM schedulers
+  for every core, set the `POLARS_MAX_THREADS` environment variable
+  before the VM starts:
+
+      POLARS_MAX_THREADS=4 iex -S mix
+
+  As the pool starts lazily, the variable can also be set from Elixir,
+  for example when your application starts, as long as it happens
+  before the first call to Polars:
+
+      System.put_env("POLARS_MAX_THREADS", "4")
+
+  The pool cannot be resized once it has started, so setting the
+  variable afterwards has no effect. See `get_max_threads/0`.
+
+  ## Schedulers
+
+  Reading and writing files runs on dirty IO schedulers, so slow
+  reads, such as from S3, do not hold the dirty CPU schedulers used
+  by computations. Lazy scans, which read the file metadata or infer
+  its schema, can run on dirty CPU schedulers instead, for example
+  when the dirty IO schedulers are busy with other work:
+
+      config :explorer, scan_scheduler: :dirty_cpu
+
+  The scheduler is chosen at compile time, so Explorer must be
+  recompiled after changing it.
   """
+
+  alias Explorer.PolarsBackend.Native
+  alias Explorer.PolarsBackend.Shared
+
+  @doc """
+  Runs `fun` with the global string cache enabled.
+
+  Categorical series created while the cache is enabled share their
+  categories, so they can be compared, joined and concatenated even
+  when they come from different data frames.
+
+  Calls can be nested. The cache stays enabled until the outermost
+  call returns or raises.
+  """
+  def with_string_cache(fun) when is_function(fun, 0) do
+    holder = Native.hold_string_cache()
+
+    try do
+      fun.()
+    after
+      Native.release_string_cache(holder)
+    end
+  end
+
+  @doc """
+  Creates a token to cancel queries collected with `collect/2`.
+
+  The same token can be given to many queries, which are all
+  cancelled by `cancel/1`.
+  """
+  def cancel_token, do: Native.cancel_token_new()
+
+  @doc """
+  Cancels the queries collected with `token`.
+
+  Polars only checks for cancellation between operations, so a
+  running query may take a moment to stop. Cancelling is permanent:
+  queries collected with the token afterwards are cancelled right away.
+  """
+  def cancel(token) do
+    Native.cancel(token)
+    :ok
+  end
+
+  @doc """
+  Collects a lazy data frame, like `Explorer.DataFrame.collect/1`, so
+  it can be cancelled with `cancel/1` from another process.
+
+  Raises a `RuntimeError` if the query is cancelled.
+
+  The query always runs on the in-memory engine, even if streaming
+  was enabled, as the streaming engine cannot be stopped.
+
+  ## Examples
+
+      token = Explorer.PolarsBackend.cancel_token()
+      task = Task.async(fn -> Explorer.PolarsBackend.collect(ldf, token) end)
+      Explorer.PolarsBackend.cancel(token)
+
+  """
+  def collect(%Explorer.DataFrame{data: %Explorer.PolarsBackend.LazyFrame{}} = ldf, token) do
+    Shared.apply_dataframe(ldf, ldf, :lf_compute, [token, Shared.progress()])
+  end
+
+  @doc """
+  Runs `fun`, reporting the progress of the reads, writes and queries
+  it makes to `pid`.
+
+  `pid` gets `{ref, {:progress, rows}}` messages, where `rows` is the
+  number of rows read or written so far. Progress is reported by:
+
+    * `Explorer.DataFrame.from_parquet/2` on local files, after each
+      row group, which are then read one at a time
+
+    * `Explorer.DataFrame.to_csv/3` and `Explorer.DataFrame.to_parquet/3`
+      on local files, after each batch of rows for CSV, and after each
+      row group for Parquet. Lazy frames must be written with
+      `streaming: false`, as streaming writes cannot report progress
+      and return an error instead
+
+    * `Explorer.DataFrame.collect/1` and `collect/2`, only once the
+      query is done, as Polars doesn't tell how far a query is while
+      it runs
+
+  Returns the result of `fun`.
+
+  ## Examples
+
+      ref = make_ref()
+
+      Explorer.PolarsBackend.with_progress(self(), ref, fn ->
+        Explorer.DataFrame.to_parquet!(df, "data.parquet")
+      end)
+
+      receive do
+        {^ref, {:progress, rows}} -> rows
+      end
+
+  """
+  def with_progress(pid, ref, fun) when is_pid(pid) and is_function(fun, 0) do
+    previous = Shared.put_progress({pid, ref})
+
+    try do
+      fun.()
+    after
+      Shared.put_progress(previous)
+    end
+  end
+
+  @doc """
+  Returns the number of threads of the Polars thread pool.
+
+  This starts the pool if it has not started yet, which fixes its size.
+  """
+  def get_max_threads, do: Native.get_max_threads()
+
+  @doc """
+  Raises, as the Polars thread pool cannot be resized at runtime.
+
+  Set the `POLARS_MAX_THREADS` environment variable before the first
+  call to Polars instead, as described in the module documentation.
+  """
+  def set_max_threads(max_threads) when is_integer(max_threads) and max_threads > 0 do
+    Shared.apply(:set_max_threads, [max_threads])
+  end
 end
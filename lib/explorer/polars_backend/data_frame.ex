@@ -27,7 +27,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def from_query(conn, query, params) do
     adbc_result =
       Adbc.Connection.query_pointer(conn, query, params, fn pointer, _num_rows ->
-        Explorer.PolarsBackend.Native.df_from_arrow_stream_pointer(pointer)
+        Explorer.PolarsBackend.Native.df_from_arrow_stream_pointer(pointer, [], nil)
       end)
 
     with {:ok, df_result} <- adbc_result,
@@ -123,7 +123,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
 
     case df do
       {:ok, df} -> Shared.create_dataframe(df)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -155,9 +155,9 @@ defmodule Explorer.PolarsBackend.DataFrame do
       ) do
     <<delimiter::utf8>> = delimiter
 
-    case Native.df_to_csv(df, entry.path, header?, delimiter, quote_style) do
+    case Native.df_to_csv(df, entry.path, header?, delimiter, quote_style, Shared.progress()) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -174,7 +174,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
 
     case Native.df_to_csv_cloud(df, entry, header?, delimiter, quote_style) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -182,7 +182,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def dump_csv(%DataFrame{} = df, header?, <<delimiter::utf8>>, quote_style) do
     case Native.df_dump_csv(df.data, header?, delimiter, quote_style) do
       {:ok, string} -> {:ok, string}
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -230,7 +230,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
 
     case df do
       {:ok, df} -> Shared.create_dataframe(df)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -257,7 +257,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def from_ndjson(%Local.Entry{} = entry, infer_schema_length, batch_size) do
     case Native.df_from_ndjson(entry.path, infer_schema_length, batch_size) do
       {:ok, df} -> Shared.create_dataframe(df)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -265,7 +265,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def to_ndjson(%DataFrame{data: df}, %Local.Entry{} = entry) do
     case Native.df_to_ndjson(df, entry.path) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -273,7 +273,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def to_ndjson(%DataFrame{data: df}, %S3.Entry{} = entry) do
     case Native.df_to_ndjson_cloud(df, entry) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -281,7 +281,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def dump_ndjson(%DataFrame{} = df) do
     case Native.df_dump_ndjson(df.data) do
       {:ok, string} -> {:ok, string}
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -289,15 +289,15 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def load_ndjson(contents, infer_schema_length, batch_size) when is_binary(contents) do
     case Native.df_load_ndjson(contents, infer_schema_length, batch_size) do
       {:ok, df} -> Shared.create_dataframe(df)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
   @impl true
   def from_parquet(%S3.Entry{} = entry, max_rows, columns, _rechunk) do
     # We first read using a lazy dataframe, then we collect.
-    with {:ok, ldf} <- Native.lf_from_parquet_cloud(entry, max_rows, columns),
-         {:ok, df} <- Native.lf_compute(ldf) do
+    with {:ok, ldf} <- Shared.scan(:lf_from_parquet_cloud, [entry, max_rows, columns]),
+         {:ok, df} <- Native.lf_compute(ldf, nil, Shared.progress()) do
       Shared.create_dataframe(df)
     end
   end
@@ -326,12 +326,13 @@ defmodule Explorer.PolarsBackend.DataFrame do
         max_rows,
         columns,
         with_projection,
-        rechunk
+        rechunk,
+        Shared.progress()
       )
 
     case df do
       {:ok, df} -> Shared.create_dataframe(df)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -342,9 +343,14 @@ defmodule Explorer.PolarsBackend.DataFrame do
         {compression, compression_level},
         _streaming
       ) do
-    case Native.df_to_parquet(df, entry.path, parquet_compression(compression, compression_level)) do
+    case Native.df_to_parquet(
+           df,
+           entry.path,
+           parquet_compression(compression, compression_level),
+           Shared.progress()
+         ) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -361,7 +367,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
            parquet_compression(compression, compression_level)
          ) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -369,7 +375,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def dump_parquet(%DataFrame{data: df}, {compression, compression_level}) do
     case Native.df_dump_parquet(df, parquet_compression(compression, compression_level)) do
       {:ok, string} -> {:ok, string}
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -385,7 +391,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def load_parquet(contents) when is_binary(contents) do
     case Native.df_load_parquet(contents) do
       {:ok, df} -> Shared.create_dataframe(df)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -409,7 +415,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
 
     case Native.df_from_ipc(entry.path, columns, projection) do
       {:ok, df} -> Shared.create_dataframe(df)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -417,7 +423,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def to_ipc(%DataFrame{data: df}, %Local.Entry{} = entry, {compression, _level}, _streaming) do
     case Native.df_to_ipc(df, entry.path, maybe_atom_to_string(compression)) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -425,7 +431,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def to_ipc(%DataFrame{data: df}, %S3.Entry{} = entry, {compression, _level}, _streaming) do
     case Native.df_to_ipc_cloud(df, entry, maybe_atom_to_string(compression)) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -433,7 +439,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def dump_ipc(%DataFrame{data: df}, {compression, _level}) do
     case Native.df_dump_ipc(df, maybe_atom_to_string(compression)) do
       {:ok, string} -> {:ok, string}
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -443,7 +449,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
 
     case Native.df_load_ipc(contents, columns, projection) do
       {:ok, df} -> Shared.create_dataframe(df)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -467,7 +473,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
 
     case Native.df_from_ipc_stream(entry.path, columns, projection) do
       {:ok, df} -> Shared.create_dataframe(df)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -475,7 +481,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def to_ipc_stream(%DataFrame{data: df}, %Local.Entry{} = entry, {compression, _level}) do
     case Native.df_to_ipc_stream(df, entry.path, maybe_atom_to_string(compression)) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -483,7 +489,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def to_ipc_stream(%DataFrame{data: df}, %S3.Entry{} = entry, {compression, _level}) do
     case Native.df_to_ipc_stream_cloud(df, entry, maybe_atom_to_string(compression)) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -491,7 +497,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def dump_ipc_stream(%DataFrame{data: df}, {compression, _level}) do
     case Native.df_dump_ipc_stream(df, maybe_atom_to_string(compression)) do
       {:ok, string} -> {:ok, string}
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -501,7 +507,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
 
     case Native.df_load_ipc_stream(contents, columns, projection) do
       {:ok, df} -> Shared.create_dataframe(df)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -690,6 +696,12 @@ defmodule Explorer.PolarsBackend.DataFrame do
     |> Shared.create_dataframe!()
   end
 
+  @impl true
+  def describe(%DataFrame{} = df, percentiles) do
+    Shared.apply(:df_describe, [df.data, percentiles])
+    |> Shared.create_dataframe!()
+  end
+
   @impl true
   def sort_with(
         %DataFrame{} = df,
@@ -727,7 +739,8 @@ defmodule Explorer.PolarsBackend.DataFrame do
         maintain_order?,
         multithreaded?,
         nulls_last?,
-        df.groups
+        df.groups,
+        nil
       ])
     end
   end
@@ -754,7 +767,15 @@ defmodule Explorer.PolarsBackend.DataFrame do
 
   @impl true
   def sample(df, n, replacement, shuffle, seed) when is_integer(n) do
-    Shared.apply_dataframe(df, df, :df_sample_n, [n, replacement, shuffle, seed, df.groups])
+    Shared.apply_dataframe(df, df, :df_sample_n, [
+      n,
+      replacement,
+      shuffle,
+      seed,
+      df.groups,
+      nil,
+      false
+    ])
   end
 
   @impl true
@@ -762,7 +783,15 @@ defmodule Explorer.PolarsBackend.DataFrame do
     # Avoid grouping if the sample is of the entire DF.
     groups = if frac == 1.0, do: [], else: df.groups
 
-    Shared.apply_dataframe(df, df, :df_sample_frac, [frac, replacement, shuffle, seed, groups])
+    Shared.apply_dataframe(df, df, :df_sample_frac, [
+      frac,
+      replacement,
+      shuffle,
+      seed,
+      groups,
+      nil,
+      false
+    ])
   end
 
   @impl true
@@ -828,14 +857,15 @@ defmodule Explorer.PolarsBackend.DataFrame do
       id_columns,
       names_from,
       values_from,
-      names_prefix_optional
+      names_prefix_optional,
+      :first
     ])
     |> Shared.create_dataframe!()
   end
 
   @impl true
   def transpose(df, out_df, keep_names_as, new_col_names) do
-    Shared.apply_dataframe(df, out_df, :df_transpose, [keep_names_as, new_col_names])
+    Shared.apply_dataframe(df, out_df, :df_transpose, [keep_names_as, new_col_names, nil])
   end
 
   @impl true
@@ -856,14 +886,14 @@ defmodule Explorer.PolarsBackend.DataFrame do
 
   @impl true
   def correlation(df, out_df, method) do
-    pairwise(df, out_df, fn left, right ->
-      PolarsSeries.correlation(left, right, method)
-    end)
+    [column_name | cols] = out_df.names
+    Shared.apply_dataframe(df, out_df, :df_correlation, [cols, method, column_name])
   end
 
   @impl true
   def covariance(df, out_df, ddof) do
-    pairwise(df, out_df, fn left, right -> PolarsSeries.covariance(left, right, ddof) end)
+    [column_name | cols] = out_df.names
+    Shared.apply_dataframe(df, out_df, :df_covariance, [cols, ddof, column_name])
   end
 
   # Two or more table verbs
@@ -913,11 +943,42 @@ defmodule Explorer.PolarsBackend.DataFrame do
     |> LazyFrame.compute()
   end
 
+  @impl true
+  def map_groups(%DataFrame{} = df, fun) do
+    ref = make_ref()
+    Shared.apply(:df_group_by_apply, [df.data, df.groups, self(), ref])
+
+    ref
+    |> receive_groups([])
+    |> Enum.map(fn group ->
+      case fun.(Shared.create_dataframe!(group)) do
+        %DataFrame{} = result ->
+          DataFrame.collect(result).data
+
+        other ->
+          raise ArgumentError,
+                "map_groups/2 expects the function to return a dataframe, got: #{inspect(other)}"
+      end
+    end)
+    |> then(&Shared.apply(:df_concat_rows, [&1]))
+    |> Shared.create_dataframe!()
+  end
+
+  # The NIF only returns once every group has been sent.
+  defp receive_groups(ref, acc) do
+    receive do
+      {^ref, {:group, group}} -> receive_groups(ref, [group | acc])
+      {^ref, :done} -> Enum.reverse(acc)
+    end
+  end
+
   # Inspect
 
   @impl true
   def inspect(df, opts) do
-    Explorer.Backend.DataFrame.inspect(df, "Polars", n_rows(df), opts)
+    Explorer.Backend.DataFrame.inspect(df, "Polars", n_rows(df), opts,
+      glimpse: &Shared.apply(:df_glimpse, [df.data, &1])
+    )
   end
 
   # SQL
@@ -934,28 +995,7 @@ defmodule Explorer.PolarsBackend.DataFrame do
   def re_dtype(regex_as_string) when is_binary(regex_as_string) do
     case Explorer.PolarsBackend.Native.df_re_dtype(regex_as_string) do
       {:ok, dtype} -> dtype
-      {:error, error} -> raise error
+      {:error, error} -> raise Shared.runtime_error(error)
     end
   end
-
-  # helpers
-
-  defp pairwise(df, out_df, operation) do
-    [column_name | cols] = out_df.names
-
-    pairwise_results =
-      Enum.map(cols, fn left ->
-        corr_series =
-          cols
-          |> Enum.map(fn right -> operation.(df[left], df[right]) end)
-          |> Shared.from_list({:f, 64})
-          |> Shared.create_series()
-
-        {left, corr_series}
-      end)
-
-    names_series = cols |> Shared.from_list(:string) |> Shared.create_series()
-
-    from_series([{column_name, names_series} | pairwise_results])
-  end
 end
@@ -30,7 +30,7 @@ defmodule Explorer.PolarsBackend.LazyFrame do
   def lazy(ldf), do: ldf
 
   @impl true
-  def compute(ldf), do: Shared.apply_dataframe(ldf, ldf, :lf_compute, [])
+  def compute(ldf), do: Shared.apply_dataframe(ldf, ldf, :lf_compute, [nil, Shared.progress()])
 
   @impl true
   def from_tabular(tabular, dtypes),
@@ -76,7 +76,9 @@ defmodule Explorer.PolarsBackend.LazyFrame do
         df = Explorer.Backend.DataFrame.new(df, ldf.names, ldf.dtypes)
         df = %{df | groups: ldf.groups}
 
-        Explorer.Backend.DataFrame.inspect(df, "LazyPolars", nil, opts)
+        Explorer.Backend.DataFrame.inspect(df, "LazyPolars", nil, opts,
+          glimpse: &Shared.apply(:df_glimpse, [df.data, &1])
+        )
 
       {:error, error} ->
         raise "inspection error: #{inspect(error)}"
@@ -154,7 +156,7 @@ defmodule Explorer.PolarsBackend.LazyFrame do
         else: infer_schema_length
 
     result =
-      Native.lf_from_csv(
+      Shared.scan(:lf_from_csv, [
         entry.path,
         infer_schema_length,
         header?,
@@ -168,11 +170,11 @@ defmodule Explorer.PolarsBackend.LazyFrame do
         nil_values,
         parse_dates,
         char_byte(eol_delimiter)
-      )
+      ])
 
     case result do
       {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -204,25 +206,25 @@ defmodule Explorer.PolarsBackend.LazyFrame do
 
   @impl true
   def from_parquet(%S3.Entry{} = entry, max_rows, columns, _rechunk) do
-    case Native.lf_from_parquet_cloud(entry, max_rows, columns) do
+    case Shared.scan(:lf_from_parquet_cloud, [entry, max_rows, columns]) do
       {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
   @impl true
   def from_parquet(%HTTP.Entry{url: url}, max_rows, columns, _rechunk) do
-    case Native.lf_from_parquet(url, max_rows, columns) do
+    case Shared.scan(:lf_from_parquet, [url, max_rows, columns]) do
       {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
   @impl true
   def from_parquet(%Local.Entry{} = entry, max_rows, columns, _rechunk) do
-    case Native.lf_from_parquet(entry.path, max_rows, columns) do
+    case Shared.scan(:lf_from_parquet, [entry.path, max_rows, columns]) do
       {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -234,17 +236,17 @@ defmodule Explorer.PolarsBackend.LazyFrame do
 
   @impl true
   def from_ndjson(%Local.Entry{} = entry, infer_schema_length, batch_size) do
-    case Native.lf_from_ndjson(entry.path, infer_schema_length, batch_size) do
+    case Shared.scan(:lf_from_ndjson, [entry.path, infer_schema_length, batch_size]) do
       {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
   @impl true
   def from_ndjson(%HTTP.Entry{url: url}, infer_schema_length, batch_size) do
-    case Native.lf_from_ndjson(url, infer_schema_length, batch_size) do
+    case Shared.scan(:lf_from_ndjson, [url, infer_schema_length, batch_size]) do
       {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -256,9 +258,9 @@ defmodule Explorer.PolarsBackend.LazyFrame do
 
   @impl true
   def from_ipc(%Local.Entry{} = entry, columns) when is_nil(columns) do
-    case Native.lf_from_ipc(entry.path) do
+    case Shared.scan(:lf_from_ipc, [entry.path]) do
       {:ok, polars_ldf} -> Shared.create_dataframe(polars_ldf)
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -354,9 +356,18 @@ defmodule Explorer.PolarsBackend.LazyFrame do
   def to_csv(%DF{} = ldf, %Local.Entry{} = entry, header?, delimiter, quote_style, streaming) do
     <<delimiter::utf8>> = delimiter
 
-    case Native.lf_to_csv(ldf.data, entry.path, header?, delimiter, quote_style, streaming) do
+    case Native.lf_to_csv(
+           ldf.data,
+           entry.path,
+           header?,
+           delimiter,
+           quote_style,
+           streaming,
+           nil,
+           Shared.progress()
+         ) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -373,10 +384,12 @@ defmodule Explorer.PolarsBackend.LazyFrame do
            ldf.data,
            entry.path,
            Shared.parquet_compression(compression, level),
-           streaming
+           streaming,
+           nil,
+           Shared.progress()
          ) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -388,7 +401,7 @@ defmodule Explorer.PolarsBackend.LazyFrame do
            Shared.parquet_compression(compression, level)
          ) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -401,9 +414,9 @@ defmodule Explorer.PolarsBackend.LazyFrame do
 
   @impl true
   def to_ipc(%DF{} = ldf, %Local.Entry{} = entry, {compression, _level}, streaming) do
-    case Native.lf_to_ipc(ldf.data, entry.path, Atom.to_string(compression), streaming) do
+    case Native.lf_to_ipc(ldf.data, entry.path, Atom.to_string(compression), streaming, nil) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -415,7 +428,7 @@ defmodule Explorer.PolarsBackend.LazyFrame do
            Atom.to_string(compression)
          ) do
       {:ok, _} -> :ok
-      {:error, error} -> {:error, RuntimeError.exception(error)}
+      {:error, error} -> {:error, Shared.runtime_error(error)}
     end
   end
 
@@ -465,7 +478,8 @@ defmodule Explorer.PolarsBackend.LazyFrame do
           expressions,
           directions,
           maintain_order?,
-          nulls_last?
+          nulls_last?,
+          nil
         ]
       )
     else
@@ -543,7 +557,11 @@ defmodule Explorer.PolarsBackend.LazyFrame do
 
   @impl true
   def unnest(%DF{} = df, %DF{} = out_df, columns),
-    do: Shared.apply_dataframe(df, out_df, :lf_unnest, [columns])
+    do: Shared.apply_dataframe(df, out_df, :lf_unnest, [columns, nil])
+
+  @impl true
+  def describe(%DF{} = ldf, percentiles),
+    do: ldf |> compute() |> Eager.describe(percentiles)
 
   # Groups
 
@@ -619,7 +637,7 @@ defmodule Explorer.PolarsBackend.LazyFrame do
          {:ok, dtypes} <- Native.lf_dtypes(polars_lf) do
       Explorer.Backend.DataFrame.new(polars_lf, names, dtypes)
     else
-      {:error, polars_error} -> raise polars_error
+      {:error, polars_error} -> raise Shared.runtime_error(polars_error)
     end
   end
 
@@ -639,6 +657,7 @@ defmodule Explorer.PolarsBackend.LazyFrame do
     covariance: 3,
     nil_count: 1,
     dummies: 3,
+    map_groups: 2,
     dump_csv: 4,
     dump_ipc: 2,
     dump_ipc_stream: 2,
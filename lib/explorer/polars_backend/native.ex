@@ -57,7 +57,7 @@ defmodule Explorer.PolarsBackend.Native do
 
   defstruct [:inner]
 
-  def df_from_arrow_stream_pointer(_stream_ptr), do: err()
+  def df_from_arrow_stream_pointer(_stream_ptr, _dtypes, _acc), do: err()
 
   def df_sort_by(_df, _by, _reverse, _maintain_order?, _multithreaded?, _nulls_last?, _groups),
     do: err()
@@ -69,11 +69,16 @@ defmodule Explorer.PolarsBackend.Native do
         _maintain_order?,
         _multithreaded?,
         _nulls_last?,
-        _groups
+        _groups,
+        _limit
       ),
       do: err()
 
   def df_concat_columns(_dfs), do: err()
+  def df_concat_rows(_dfs), do: err()
+  def df_correlation(_df, _columns, _method, _column_name), do: err()
+  def df_covariance(_df, _columns, _ddof, _column_name), do: err()
+  def df_describe(_df, _percentiles), do: err()
   def df_drop(_df, _name), do: err()
   def df_dtypes(_df), do: err()
   def df_dump_csv(_df, _has_headers, _delimiter, _quote_style), do: err()
@@ -81,6 +86,8 @@ defmodule Explorer.PolarsBackend.Native do
   def df_dump_parquet(_df, _compression), do: err()
   def df_dump_ipc(_df, _compression), do: err()
   def df_dump_ipc_stream(_df, _compression), do: err()
+  def df_explode(_df, _columns), do: err()
+  def df_extend(_target, _other), do: err()
 
   def df_from_csv(
         _filename,
@@ -110,13 +117,17 @@ defmodule Explorer.PolarsBackend.Native do
         _stop_after_n_rows,
         _columns,
         _projection,
-        _rechunk
+        _rechunk,
+        _progress
       ),
       do: err()
 
   def df_from_series(_columns), do: err()
+  def df_glimpse(_df, _max_items_per_column), do: err()
+  def df_group_by_apply(_df, _keys, _pid, _reference), do: err()
   def df_group_indices(_df, _column_names), do: err()
   def df_groups(_df, _column_names), do: err()
+  def df_hash_rows(_df, _seed, _columns), do: err()
 
   def df_load_csv(
         _binary,
@@ -145,18 +156,39 @@ defmodule Explorer.PolarsBackend.Native do
   def df_mask(_df, _mask), do: err()
   def df_n_rows(_df), do: err()
   def df_names(_df), do: err()
-  def df_pivot_wider(_df, _id_columns, _pivot_column, _values_column, _names_prefix), do: err()
+  def df_partition_by(_df, _columns, _include_key, _as_map), do: err()
+  def df_pivot_wider(
+        _df,
+        _id_columns,
+        _pivot_column,
+        _values_column,
+        _names_prefix,
+        _aggregation
+      ),
+      do: err()
   def df_pull(_df, _name), do: err()
   def df_put_column(_df, _series), do: err()
-  def df_sample_frac(_df, _frac, _with_replacement, _shuffle, _seed, _groups), do: err()
-  def df_sample_n(_df, _n, _with_replacement, _shuffle, _seed, _groups), do: err()
+  def df_sample_frac(
+        _df,
+        _frac,
+        _with_replacement,
+        _shuffle,
+        _seed,
+        _groups,
+        _weights,
+        _stratified
+      ),
+      do: err()
+  def df_sample_n(_df, _n, _with_replacement, _shuffle, _seed, _groups, _weights, _stratified),
+    do: err()
+  def df_shuffle(_df, _seed), do: err()
   def df_select_at_idx(_df, _idx), do: err()
   def df_shape(_df), do: err()
   def df_slice(_df, _offset, _length, _groups), do: err()
   def df_slice_by_indices(_df, _indices, _groups), do: err()
   def df_slice_by_series(_df, _series, _groups), do: err()
-  def df_transpose(_df, _keep_names_as, _new_col_names), do: err()
-  def df_to_csv(_df, _filename, _has_headers, _delimiter, _quote_style), do: err()
+  def df_transpose(_df, _keep_names_as, _new_col_names, _names_from), do: err()
+  def df_to_csv(_df, _filename, _has_headers, _delimiter, _quote_style, _progress), do: err()
   def df_to_csv_cloud(_df, _ex_entry, _has_headers, _delimiter, _quote_style), do: err()
   def df_to_dummies(_df, _columns), do: err()
   def df_to_ipc(_df, _filename, _compression), do: err()
@@ -166,10 +198,19 @@ defmodule Explorer.PolarsBackend.Native do
   def df_lazy(_df), do: err()
   def df_to_ndjson(_df, _filename), do: err()
   def df_to_ndjson_cloud(_df, _ex_entry), do: err()
-  def df_to_parquet(_df, _filename, _compression), do: err()
+  def df_to_parquet(_df, _filename, _compression, _progress), do: err()
   def df_to_parquet_cloud(_df, _ex_entry, _compression), do: err()
+  def df_to_rows_chunked(_df, _batch_size, _atom_keys?, _pid, _ref), do: err()
+  def df_unique(_df, _subset, _keep), do: err()
+  def df_unnest(_df, _columns, _separator), do: err()
+  def df_upsample(_df, _time_column, _every, _groups), do: err()
+  def df_upsert(_target, _updates, _on), do: err()
   def df_width(_df), do: err()
+  def df_with_row_index(_df, _name, _offset), do: err()
   def df_estimated_size(_df), do: err()
+  def df_chunk_info(_df), do: err()
+  def df_column_stats(_df), do: err()
+  def df_rechunk(_df), do: err()
   def df_nil_count(_df), do: err()
   def df_re_dtype(_pattern), do: err()
 
@@ -188,27 +229,108 @@ defmodule Explorer.PolarsBackend.Native do
 
   def expr_log(_lazy_series, _base), do: err()
   def expr_log_natural(_lazy_series), do: err()
+  def expr_log1p(_lazy_series), do: err()
+  def expr_expm1(_lazy_series), do: err()
 
   # Then we generate for some specific expressions
   def expr_alias(_ex_expr, _alias_name), do: err()
+  def expr_base_utc_offset(_expr), do: err()
+  def expr_binary_contains(_expr, _pattern), do: err()
+  def expr_binary_ends_with(_expr, _suffix), do: err()
+  def expr_binary_size(_expr), do: err()
+  def expr_binary_starts_with(_expr, _prefix), do: err()
+  def expr_convert_time_zone(_expr, _time_zone), do: err()
+  def expr_days_in_month(_expr), do: err()
+  def expr_dst_offset(_expr), do: err()
+  def expr_dt_offset_by(_expr, _interval), do: err()
+  def expr_dt_round(_expr, _every), do: err()
+  def expr_dt_to_date(_expr), do: err()
+  def expr_dt_to_time(_expr), do: err()
+  def expr_is_leap_year(_expr), do: err()
+  def expr_iso_year(_expr), do: err()
+  def expr_microsecond(_expr), do: err()
+  def expr_millisecond(_expr), do: err()
+  def expr_month_end(_expr), do: err()
+  def expr_month_start(_expr), do: err()
+  def expr_nanosecond(_expr), do: err()
   def expr_nil(), do: err()
   def expr_atom(_atom), do: err()
+  def expr_base64_decode(_expr, _strict), do: err()
+  def expr_base64_encode(_expr), do: err()
   def expr_boolean(_bool), do: err()
+  def expr_contains_any(_expr, _patterns, _ascii_case_insensitive), do: err()
   def expr_date(_date), do: err()
   def expr_naive_datetime(_datetime), do: err()
   def expr_datetime(_datetime), do: err()
+  def expr_dt_truncate(_expr, _every), do: err()
   def expr_duration(_duration), do: err()
+  def expr_element(), do: err()
+  def expr_explode(_expr), do: err()
   def expr_describe_filter_plan(_df, _expr), do: err()
   def expr_float(_number), do: err()
+  def expr_hex_decode(_expr, _strict), do: err()
+  def expr_hex_encode(_expr), do: err()
   def expr_integer(_number), do: err()
+  def expr_jaro_winkler(_left, _right), do: err()
+  def expr_json_encode(_expr), do: err()
+  def expr_levenshtein(_left, _right), do: err()
+  def expr_list_eval(_expr, _inner), do: err()
+  def expr_list_get(_expr, _index), do: err()
+  def expr_list_head(_expr, _n), do: err()
+  def expr_list_max(_expr), do: err()
+  def expr_list_mean(_expr), do: err()
+  def expr_list_min(_expr), do: err()
+  def expr_list_reverse(_expr), do: err()
+  def expr_list_set_difference(_expr, _other), do: err()
+  def expr_list_set_intersection(_expr, _other), do: err()
+  def expr_list_set_union(_expr, _other), do: err()
+  def expr_list_slice(_expr, _offset, _length), do: err()
+  def expr_list_sort(_expr, _descending, _nulls_last), do: err()
+  def expr_list_sum(_expr), do: err()
+  def expr_list_tail(_expr, _n), do: err()
+  def expr_list_unique(_expr), do: err()
+  def expr_index_of(_expr, _value), do: err()
   def expr_int_range(_start, _end, _step, _dtype), do: err()
   def expr_over(_ex_expr, _groups_exprs), do: err()
+  def expr_quarter(_expr), do: err()
+  def expr_remap(_expr, _old, _new), do: err()
+  def expr_remap_strict(_expr, _old, _new, _default, _return_dtype), do: err()
+  def expr_replace_many(_expr, _patterns, _replacements, _ascii_case_insensitive), do: err()
+  def expr_replace_time_zone(_expr, _time_zone, _ambiguous, _non_existent), do: err()
   def expr_series(_series), do: err()
+  def expr_str_join(_expr, _delimiter, _ignore_nils), do: err()
+  def expr_str_to_float(_expr, _strict), do: err()
+  def expr_str_to_integer(_expr, _base, _strict), do: err()
   def expr_string(_string), do: err()
+  def expr_strip_prefix(_expr, _prefix), do: err()
+  def expr_strip_suffix(_expr, _suffix), do: err()
+  def expr_strptime_many(_expr, _formats, _precision, _time_zone, _ambiguous, _non_existent),
+    do: err()
   def expr_struct(_map), do: err()
+  def expr_timestamp(_expr, _unit), do: err()
+
+  def expr_duration_from_parts(
+        _weeks,
+        _days,
+        _hours,
+        _minutes,
+        _seconds,
+        _milliseconds,
+        _microseconds,
+        _nanoseconds,
+        _precision
+      ),
+      do: err()
+  def expr_total_days(_expr), do: err()
+  def expr_total_hours(_expr), do: err()
+  def expr_total_microseconds(_expr), do: err()
+  def expr_total_milliseconds(_expr), do: err()
+  def expr_total_minutes(_expr), do: err()
+  def expr_total_nanoseconds(_expr), do: err()
+  def expr_total_seconds(_expr), do: err()
 
   # LazyFrame
-  def lf_compute(_df), do: err()
+  def lf_compute(_df, _cancel_token, _progress), do: err()
   def lf_describe_plan(_df, _optimized), do: err()
   def lf_drop(_df, _columns), do: err()
   def lf_dtypes(_df), do: err()
@@ -219,7 +341,7 @@ defmodule Explorer.PolarsBackend.Native do
   def lf_tail(_df, _n_rows, _groups), do: err()
   def lf_slice(_df, _offset, _length, _groups), do: err()
   def lf_explode(_df, _columns), do: err()
-  def lf_unnest(_df, _columns), do: err()
+  def lf_unnest(_df, _columns, _separator), do: err()
   def lf_from_ipc(_filename), do: err()
   def lf_from_ndjson(_filename, _infer_schema_length, _batch_size), do: err()
   def lf_from_parquet(_filename, _stop_after_n_rows, _maybe_columns), do: err()
@@ -242,6 +364,19 @@ defmodule Explorer.PolarsBackend.Native do
       ),
       do: err()
 
+  # Scans have a variant that runs on dirty CPU schedulers, see
+  # `Explorer.PolarsBackend.Shared.scan/2`.
+  for {scan, arity} <- [
+        lf_from_ipc: 1,
+        lf_from_ndjson: 3,
+        lf_from_parquet: 3,
+        lf_from_parquet_cloud: 3,
+        lf_from_csv: 13
+      ] do
+    args = Macro.generate_arguments(arity, __MODULE__)
+    def unquote(:"#{scan}_cpu")(unquote_splicing(args)), do: err()
+  end
+
   def lf_filter_with(_df, _expression), do: err()
 
   def lf_sort_with(
@@ -249,7 +384,8 @@ defmodule Explorer.PolarsBackend.Native do
         _expressions,
         _directions,
         _maintain_order?,
-        _nulls_last?
+        _nulls_last?,
+        _limit
       ),
       do: err()
 
@@ -270,11 +406,23 @@ defmodule Explorer.PolarsBackend.Native do
   def lf_join(_df, _other, _left_on, _right_on, _how, _suffix), do: err()
   def lf_concat_rows(_dfs), do: err()
   def lf_concat_columns(_ldfs), do: err()
-  def lf_to_parquet(_df, _filename, _compression, _streaming), do: err()
+  def lf_to_parquet(_df, _filename, _compression, _streaming, _cancel_token, _progress),
+    do: err()
   def lf_to_parquet_cloud(_df, _filename, _compression), do: err()
-  def lf_to_ipc(_df, _filename, _compression, _streaming), do: err()
+  def lf_to_ipc(_df, _filename, _compression, _streaming, _cancel_token), do: err()
   def lf_to_ipc_cloud(_df, _cloud_entry, _compression), do: err()
-  def lf_to_csv(_df, _filename, _header, _delimiter, _quote_style, _streaming), do: err()
+  def lf_to_csv(
+        _df,
+        _filename,
+        _header,
+        _delimiter,
+        _quote_style,
+        _streaming,
+        _cancel_token,
+        _progress
+      ),
+      do: err()
+
   def lf_sql(_df, _sql_string, _table_name), do: err()
 
   # Series
@@ -285,11 +433,20 @@ defmodule Explorer.PolarsBackend.Native do
   def s_any(_s), do: err()
   def s_argmax(_s), do: err()
   def s_argmin(_s), do: err()
+  def s_index_of(_s, _value), do: err()
   def s_argsort(_s, _descending?, _maintain_order?, _multithreaded?, _nulls_last?), do: err()
+  def s_base64_decode(_s, _strict), do: err()
+  def s_base64_encode(_s), do: err()
+  def s_binary_contains(_s, _pattern), do: err()
+  def s_binary_ends_with(_s, _suffix), do: err()
+  def s_binary_size(_s), do: err()
+  def s_binary_starts_with(_s, _prefix), do: err()
   def s_cast(_s, _dtype), do: err()
   def s_categories(_s), do: err()
   def s_categorise(_s, _s_categories), do: err()
+  def s_set_categorical_ordering(_s, _ordering), do: err()
   def s_coalesce(_s, _other), do: err()
+  def s_zip_with(_s, _mask, _other), do: err()
   def s_concat(_series_list), do: err()
   def s_contains(_s, _pattern, _is_literal), do: err()
   def s_count_matches(_s, _pattern, _is_literal), do: err()
@@ -307,11 +464,17 @@ defmodule Explorer.PolarsBackend.Native do
   def s_dtype(_s), do: err()
   def s_equal(_s, _rhs), do: err()
   def s_exp(_s), do: err()
+  def s_expm1(_s), do: err()
   def s_abs(_s), do: err()
   def s_strptime(_s, _format_string, _precision), do: err()
+  def s_strptime_many(_s, _formats, _precision, _time_zone, _ambiguous, _non_existent),
+    do: err()
   def s_strftime(_s, _format_string), do: err()
   def s_clip_integer(_s, _min, _max), do: err()
   def s_clip_float(_s, _min, _max), do: err()
+  def s_clip_date(_s, _min, _max), do: err()
+  def s_clip_naive_datetime(_s, _min, _max), do: err()
+  def s_clip_datetime(_s, _min, _max), do: err()
   def s_fill_missing_with_strategy(_s, _strategy), do: err()
   def s_fill_missing_with_boolean(_s, _value), do: err()
   def s_fill_missing_with_bin(_s, _value), do: err()
@@ -329,13 +492,16 @@ defmodule Explorer.PolarsBackend.Native do
   def s_is_nan(_s), do: err()
   def s_is_not_null(_s), do: err()
   def s_is_null(_s), do: err()
+  def s_jaro_winkler(_s, _other), do: err()
   def s_less(_s, _rhs), do: err()
   def s_less_equal(_s, _rhs), do: err()
+  def s_levenshtein(_s, _other), do: err()
   def s_lstrip(_s, _string), do: err()
   def s_mask(_s, _filter), do: err()
   def s_max(_s), do: err()
   def s_mean(_s), do: err()
   def s_median(_s), do: err()
+  def s_dot(_s, _other), do: err()
   def s_product(_s), do: err()
   def s_min(_s), do: err()
   def s_mode(_s), do: err()
@@ -346,18 +512,24 @@ defmodule Explorer.PolarsBackend.Native do
   def s_nil_count(_s), do: err()
   def s_not(_s), do: err()
   def s_from_list_null(_name, _val), do: err()
+  def s_from_list_null_typed(_name, _length, _dtype), do: err()
   def s_from_list_bool(_name, _val), do: err()
   def s_from_list_date(_name, _val), do: err()
   def s_from_list_time(_name, _val), do: err()
   def s_from_list_naive_datetime(_name, _val, _precision), do: err()
   def s_from_list_datetime(_name, _val, _precision, _tz), do: err()
   def s_from_list_duration(_name, _val, _precision), do: err()
+  def s_from_list_enum(_name, _val, _categories), do: err()
+  def s_from_list_of_maps(_name, _val, _dtype), do: err()
+  def s_date_range(_name, _start, _stop, _interval, _closed), do: err()
+  def s_datetime_range(_name, _start, _stop, _interval, _closed, _precision, _tz), do: err()
   def s_from_list_f32(_name, _val), do: err()
   def s_from_list_f64(_name, _val), do: err()
   def s_from_list_s8(_name, _val), do: err()
   def s_from_list_s16(_name, _val), do: err()
   def s_from_list_s32(_name, _val), do: err()
   def s_from_list_s64(_name, _val), do: err()
+  def s_from_list_s128(_name, _val), do: err()
   def s_from_list_u8(_name, _val), do: err()
   def s_from_list_u16(_name, _val), do: err()
   def s_from_list_u32(_name, _val), do: err()
@@ -365,6 +537,7 @@ defmodule Explorer.PolarsBackend.Native do
   def s_from_list_str(_name, _val), do: err()
   def s_from_list_binary(_name, _val), do: err()
   def s_from_list_categories(_name, _val), do: err()
+  def s_from_list_categories_with_ordering(_name, _val, _ordering), do: err()
   def s_from_list_decimal(_name, _val, _precision, _scale), do: err()
   def s_from_list_of_series(_name, _val, _dtype), do: err()
   def s_from_list_of_series_as_structs(_name, _val, _dtype), do: err()
@@ -374,10 +547,17 @@ defmodule Explorer.PolarsBackend.Native do
   def s_from_binary_s16(_name, _val), do: err()
   def s_from_binary_s32(_name, _val), do: err()
   def s_from_binary_s64(_name, _val), do: err()
+  def s_from_binary_s128(_name, _val), do: err()
   def s_from_binary_u8(_name, _val), do: err()
   def s_from_binary_u16(_name, _val), do: err()
   def s_from_binary_u32(_name, _val), do: err()
   def s_from_binary_u64(_name, _val), do: err()
+  def s_from_binary_date(_name, _val), do: err()
+  def s_from_binary_time(_name, _val), do: err()
+  def s_from_binary_naive_datetime(_name, _val, _precision), do: err()
+  def s_from_binary_datetime(_name, _val, _precision, _tz), do: err()
+  def s_from_binary_duration(_name, _val, _precision), do: err()
+  def s_from_pointer(_name, _owner, _pointer, _size, _dtype), do: err()
   def s_not_equal(_s, _rhs), do: err()
   def s_or(_s, _s2), do: err()
   def s_peak_max(_s), do: err()
@@ -385,9 +565,11 @@ defmodule Explorer.PolarsBackend.Native do
   def s_select(_pred, _on_true, _on_false), do: err()
   def s_log_natural(_s_argument), do: err()
   def s_log(_s_argument, _base_as_float), do: err()
+  def s_log1p(_s), do: err()
   def s_quantile(_s, _quantile, _strategy), do: err()
   def s_quotient(_s, _rhs), do: err()
   def s_remainder(_s, _rhs), do: err()
+  def s_rechunk(_s), do: err()
   def s_rename(_s, _name), do: err()
   def s_reverse(_s), do: err()
   def s_round(_s, _decimals), do: err()
@@ -395,30 +577,45 @@ defmodule Explorer.PolarsBackend.Native do
   def s_floor(_s), do: err()
   def s_ceil(_s), do: err()
   def s_rstrip(_s, _string), do: err()
-  def s_rank(_s, _method, _descending, _seed), do: err()
+  def s_rank(_s, _method, _descending, _seed, _percentage), do: err()
   def s_replace(_s, _pattern, _replacement, _literal), do: err()
-  def s_sample_n(_s, _n, _replace, _shuffle, _seed), do: err()
-  def s_sample_frac(_s, _frac, _replace, _shuffle, _seed), do: err()
+  def s_sample_n(_s, _n, _replace, _shuffle, _seed, _weights), do: err()
+  def s_sample_frac(_s, _frac, _replace, _shuffle, _seed, _weights), do: err()
+  def s_shuffle(_s, _seed), do: err()
   def s_series_equal(_s, _other, _null_equal), do: err()
   def s_size(_s), do: err()
   def s_slice(_s, _offset, _length), do: err()
   def s_slice_by_indices(_s, _indices), do: err()
   def s_slice_by_series(_s, _series), do: err()
+  def s_scatter(_s, _indices, _values), do: err()
   def s_sort(_s, _descending?, _maintain_order?, _multithreaded?, _nulls_last?), do: err()
   def s_standard_deviation(_s, _ddof), do: err()
   def s_strip(_s, _string), do: err()
+  def s_strip_prefix(_s, _prefix), do: err()
+  def s_strip_suffix(_s, _suffix), do: err()
   def s_subtract(_s, _other), do: err()
   def s_sum(_s), do: err()
   def s_tail(_s, _length), do: err()
   def s_shift(_s, _offset), do: err()
+  def s_pct_change(_s, _n), do: err()
   def s_at(_s, _rhs), do: err()
   def s_at_every(_s, _n), do: err()
+  def s_struct_to_maps(_s, _atom_keys?), do: err()
   def s_to_list(_s), do: err()
+  def s_to_list_chunked(_s, _batch_size, _pid, _ref), do: err()
+  def s_builder_new(_name, _dtype), do: err()
+  def s_builder_append_list(_builder, _chunk), do: err()
+  def s_builder_append_series(_builder, _chunk), do: err()
+  def s_builder_finish(_builder), do: err()
   def s_downcase(_s), do: err()
   def s_to_iovec(_s), do: err()
+  def s_to_pointer(_s), do: err()
   def s_upcase(_s), do: err()
   def s_unordered_distinct(_s), do: err()
-  def s_frequencies(_s), do: err()
+  def s_is_duplicated(_s), do: err()
+  def s_is_unique(_s), do: err()
+  def s_frequencies(_s, _sort_by, _descending, _include_proportions), do: err()
+  def s_unique_counts(_s), do: err()
 
   def s_cut(
         _s,
@@ -464,11 +661,36 @@ defmodule Explorer.PolarsBackend.Native do
   def s_day_of_week(_s), do: err()
   def s_day_of_year(_s), do: err()
   def s_week_of_year(_s), do: err()
+  def s_iso_year(_s), do: err()
   def s_month(_s), do: err()
+  def s_quarter(_s), do: err()
   def s_year(_s), do: err()
   def s_hour(_s), do: err()
   def s_minute(_s), do: err()
   def s_second(_s), do: err()
+  def s_millisecond(_s), do: err()
+  def s_microsecond(_s), do: err()
+  def s_nanosecond(_s), do: err()
+  def s_dt_truncate(_s, _every), do: err()
+  def s_dt_round(_s, _every), do: err()
+  def s_dt_offset_by(_s, _interval), do: err()
+  def s_month_start(_s), do: err()
+  def s_month_end(_s), do: err()
+  def s_days_in_month(_s), do: err()
+  def s_is_leap_year(_s), do: err()
+  def s_convert_time_zone(_s, _time_zone), do: err()
+  def s_replace_time_zone(_s, _time_zone, _ambiguous, _non_existent), do: err()
+  def s_base_utc_offset(_s), do: err()
+  def s_dst_offset(_s), do: err()
+  def s_dt_to_date(_s), do: err()
+  def s_dt_to_time(_s), do: err()
+  def s_total_days(_s), do: err()
+  def s_total_hours(_s), do: err()
+  def s_total_minutes(_s), do: err()
+  def s_total_seconds(_s), do: err()
+  def s_total_milliseconds(_s), do: err()
+  def s_total_microseconds(_s), do: err()
+  def s_total_nanoseconds(_s), do: err()
   def s_sin(_s), do: err()
   def s_cos(_s), do: err()
   def s_tan(_s), do: err()
@@ -480,7 +702,9 @@ defmodule Explorer.PolarsBackend.Native do
 
   def s_join(_s, _separator), do: err()
   def s_lengths(_s), do: err()
+  def s_explode(_s), do: err()
   def s_member(_s, _value, _inner_dtype), do: err()
+  def s_list_get(_s, _index), do: err()
 
   def s_field(_s, _name), do: err()
   def s_json_decode(_s, _dtype), do: err()
@@ -489,5 +713,22 @@ defmodule Explorer.PolarsBackend.Native do
   def message_on_gc(_pid, _payload), do: err()
   def is_message_on_gc(_term), do: err()
 
+  def enable_string_cache, do: err()
+  def disable_string_cache, do: err()
+  def using_string_cache, do: err()
+  def hold_string_cache, do: err()
+  def release_string_cache(_holder), do: err()
+
+  def get_max_threads, do: err()
+  def set_max_threads(_max_threads), do: err()
+
+  def cancel_token_new, do: err()
+  def cancel(_token), do: err()
+
+  def memory_stats, do: err()
+  def native_stats, do: err()
+  def native_stats_enable(_enabled?), do: err()
+  def native_stats_reset, do: err()
+
   defp err, do: :erlang.nif_error(:nif_not_loaded)
 end
@@ -113,17 +113,17 @@ defmodule Explorer.PolarsBackend.Series do
 
   @impl true
   def sample(series, n, replacement, shuffle, seed) when is_integer(n) do
-    Shared.apply_series(series, :s_sample_n, [n, replacement, shuffle, seed])
+    Shared.apply_series(series, :s_sample_n, [n, replacement, shuffle, seed, nil])
   end
 
   @impl true
   def sample(series, frac, replacement, shuffle, seed) when is_float(frac) do
-    Shared.apply_series(series, :s_sample_frac, [frac, replacement, shuffle, seed])
+    Shared.apply_series(series, :s_sample_frac, [frac, replacement, shuffle, seed, nil])
   end
 
   @impl true
   def rank(series, method, descending, seed) do
-    Shared.apply_series(series, :s_rank, [method, descending, seed])
+    Shared.apply_series(series, :s_rank, [method, descending, seed, false])
   end
 
   @impl true
@@ -538,7 +538,7 @@ defmodule Explorer.PolarsBackend.Series do
   end
 
   def frequencies(%Series{} = series) do
-    Shared.apply(:s_frequencies, [series.data])
+    Shared.apply(:s_frequencies, [series.data, :counts, true, false])
     |> Shared.create_dataframe!()
     |> DataFrame.rename(["values", "counts"])
   end
@@ -559,11 +559,11 @@ defmodule Explorer.PolarsBackend.Series do
       {:ok, polars_df} ->
         Shared.create_dataframe!(polars_df)
 
-      {:error, "Polars Error: lengths don't match: " <> _rest} ->
+      {:error, {:shape_mismatch, _message, _metadata}} ->
         raise ArgumentError, "lengths don't match: labels count must equal bins count"
 
-      {:error, msg} ->
-        raise msg
+      {:error, error} ->
+        raise Shared.runtime_error(error)
     end
   end
 
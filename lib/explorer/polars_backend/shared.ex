@@ -48,7 +48,7 @@ defmodule Explorer.PolarsBackend.Shared do
           # the full picture of the result yet.
           check_df =
             if match?(%PolarsLazyFrame{}, new_df) do
-              case Native.lf_compute(new_df) do
+              case Native.lf_compute(new_df, nil, nil) do
                 {:ok, new_df} -> create_dataframe!(new_df)
                 {:error, error} -> raise runtime_error(error)
               end
@@ -87,7 +87,7 @@ defmodule Explorer.PolarsBackend.Shared do
         {:ok, dtype} ->
           dtype
 
-        {:error, reason} ->
+        {:error, {_class, reason, _metadata}} ->
           raise ArgumentError, reason
       end
 
@@ -171,6 +171,7 @@ defmodule Explorer.PolarsBackend.Shared do
       {:s, 16} -> Native.s_from_list_s16(name, list)
       {:s, 32} -> Native.s_from_list_s32(name, list)
       {:s, 64} -> Native.s_from_list_s64(name, list)
+      {:s, 128} -> Native.s_from_list_s128(name, list)
       # Unsigned integers
       {:u, 8} -> Native.s_from_list_u8(name, list)
       {:u, 16} -> Native.s_from_list_u16(name, list)
@@ -182,6 +183,7 @@ defmodule Explorer.PolarsBackend.Shared do
       :boolean -> Native.s_from_list_bool(name, list)
       :string -> Native.s_from_list_str(name, list)
       :category -> Native.s_from_list_categories(name, list)
+      {:enum, categories} -> apply(:s_from_list_enum, [name, list, categories])
       :date -> apply(:s_from_list_date, [name, list])
       :time -> apply(:s_from_list_time, [name, list])
       {:naive_datetime, precision} -> apply(:s_from_list_naive_datetime, [name, list, precision])
@@ -234,6 +236,9 @@ defmodule Explorer.PolarsBackend.Shared do
       {:s, 64} ->
         Native.s_from_binary_s64(name, binary)
 
+      {:s, 128} ->
+        Native.s_from_binary_s128(name, binary)
+
       {:u, 8} ->
         Native.s_from_binary_u8(name, binary)
 
@@ -256,7 +261,35 @@ defmodule Explorer.PolarsBackend.Shared do
 
   defp ok({:ok, value}), do: value
 
-  defp runtime_error(error) when is_binary(error), do: RuntimeError.exception(error)
+  # Native errors come as `{class, message, metadata}`, where the class is an
+  # atom such as `:column_not_found` or `:compute`, and the metadata is a map
+  # such as `%{column: "name"}`.
+  def runtime_error({class, message, metadata})
+      when is_atom(class) and is_binary(message) and is_map(metadata),
+      do: RuntimeError.exception(message)
+
+  def runtime_error(error) when is_binary(error), do: RuntimeError.exception(error)
+
+  @scan_scheduler Application.compile_env(:explorer, :scan_scheduler, :dirty_io)
+
+  unless @scan_scheduler in [:dirty_io, :dirty_cpu] do
+    raise ArgumentError,
+          ":scan_scheduler must be :dirty_io or :dirty_cpu, got: #{inspect(@scan_scheduler)}"
+  end
+
+  # Calls a scan NIF, or its variant running on dirty CPU schedulers, as
+  # configured with `:scan_scheduler`.
+  def scan(fun, args) do
+    fun = if @scan_scheduler == :dirty_cpu, do: :"#{fun}_cpu", else: fun
+    apply(Native, fun, args)
+  end
+
+  @progress_key :explorer_polars_progress
+
+  # The `{pid, ref}` to report progress to, see `Explorer.PolarsBackend.with_progress/3`.
+  def progress, do: Process.get(@progress_key)
+
+  def put_progress(progress), do: Process.put(@progress_key, progress)
 
   def parquet_compression(nil, _), do: :uncompressed
 
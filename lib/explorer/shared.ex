@@ -9,6 +9,7 @@ defmodule Explorer.Shared do
     {:s, 16},
     {:s, 32},
     {:s, 64},
+    {:s, 128},
     {:u, 8},
     {:u, 16},
     {:u, 32},
@@ -43,7 +44,12 @@ defmodule Explorer.Shared do
   """
   def dtypes do
     @scalar_types ++
-      [{:list, :any}, {:struct, :any}, {:decimal, :pos_integer, :pos_integer}]
+      [
+        {:list, :any},
+        {:struct, :any},
+        {:enum, :any},
+        {:decimal, :pos_integer, :pos_integer}
+      ]
   end
 
   @doc """
@@ -78,6 +84,10 @@ defmodule Explorer.Shared do
   def normalise_dtype({:datetime, p, tz} = dtype) when p in @precisions and is_binary(tz),
     do: dtype
 
+  def normalise_dtype({:enum, categories} = dtype) when is_list(categories) do
+    if Enum.all?(categories, &is_binary/1), do: dtype
+  end
+
   def normalise_dtype(dtype) when dtype in @scalar_types, do: dtype
   def normalise_dtype(dtype) when dtype in [:float, :f64], do: {:f, 64}
   def normalise_dtype(dtype) when dtype in [:integer, :s64], do: {:s, 64}
@@ -85,6 +95,7 @@ defmodule Explorer.Shared do
   def normalise_dtype(:s8), do: {:s, 8}
   def normalise_dtype(:s16), do: {:s, 16}
   def normalise_dtype(:s32), do: {:s, 32}
+  def normalise_dtype(:s128), do: {:s, 128}
   def normalise_dtype(:u8), do: {:u, 8}
   def normalise_dtype(:u16), do: {:u, 16}
   def normalise_dtype(:u32), do: {:u, 32}
@@ -143,7 +154,7 @@ defmodule Explorer.Shared do
   @doc """
   Supported signed integer dtypes.
   """
-  def signed_integer_types, do: [{:s, 8}, {:s, 16}, {:s, 32}, {:s, 64}]
+  def signed_integer_types, do: [{:s, 8}, {:s, 16}, {:s, 32}, {:s, 64}, {:s, 128}]
 
   @doc """
   Supported unsigned integer dtypes.
@@ -540,6 +551,7 @@ defmodule Explorer.Shared do
   def dtype_to_string({:duration, p}), do: "duration[#{precision_string(p)}]"
   def dtype_to_string({:list, dtype}), do: "list[" <> dtype_to_string(dtype) <> "]"
   def dtype_to_string({:struct, fields}), do: "struct[#{length(fields)}]"
+  def dtype_to_string({:enum, categories}), do: "enum[#{length(categories)}]"
   def dtype_to_string({:f, size}), do: "f" <> Integer.to_string(size)
   def dtype_to_string({:s, size}), do: "s" <> Integer.to_string(size)
   def dtype_to_string({:u, size}), do: "u" <> Integer.to_string(size)
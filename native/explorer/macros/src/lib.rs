@@ -0,0 +1,142 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_macro_input, parse_quote, GenericArgument, ItemFn, Meta, MetaNameValue, PathArguments,
+    Token,
+};
+
+// Declares a NIF like `#[rustler::nif]`, which receives the same arguments.
+//
+// Rustler raises `:nif_panicked` when a NIF panics, without telling what
+// went wrong. NIFs returning `Result<_, ExplorerError>` have their body run
+// through `catch_panic` instead, so a panic is returned as
+// `{:error, {:panic, message}}`. Other NIFs are declared unchanged.
+//
+// It also accepts `measure`, which records the timings of the NIF with
+// `native_stats`, along with the rows of its result. `measure(rows = arg)`
+// counts the rows of the `arg` argument instead.
+//
+// Rustler fixes the scheduler of a NIF at compile time. With `cpu_variant`,
+// the NIF is also declared as `<name>_cpu`, which runs on a DirtyCpu
+// scheduler, so Elixir can pick either one.
+#[proc_macro_attribute]
+pub fn nif(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args with Punctuated::<Meta, Token![,]>::parse_terminated);
+    let fun = parse_macro_input!(input as ItemFn);
+
+    let (cpu_variant, args): (Vec<Meta>, Vec<Meta>) = args
+        .into_iter()
+        .partition(|arg| arg.path().is_ident("cpu_variant"));
+
+    if cpu_variant.is_empty() {
+        return expand(args, fun);
+    }
+
+    let mut variant = fun.clone();
+    variant.sig.ident = format_ident!("{}_cpu", fun.sig.ident);
+
+    let variant_args = args
+        .iter()
+        .filter(|arg| !arg.path().is_ident("schedule"))
+        .cloned()
+        .chain([parse_quote!(schedule = "DirtyCpu")])
+        .collect();
+
+    let mut output = expand(args, fun);
+    output.extend(expand(variant_args, variant));
+    output
+}
+
+fn expand(args: Vec<Meta>, mut fun: ItemFn) -> TokenStream {
+    let (measure, args): (Vec<Meta>, Vec<Meta>) = args
+        .into_iter()
+        .partition(|arg| arg.path().is_ident("measure"));
+
+    if !returns_explorer_result(&fun) {
+        if let Some(measure) = measure.first() {
+            let message = "only NIFs returning `Result<_, ExplorerError>` can be measured";
+            return syn::Error::new_spanned(measure, message)
+                .to_compile_error()
+                .into();
+        }
+
+        return declare(&args, &fun);
+    }
+
+    let block = &fun.block;
+    let body = quote!(crate::error::catch_panic(move || #block));
+
+    fun.block = match measure.first() {
+        None => parse_quote!({ #body }),
+        Some(measure) => {
+            let name = fun.sig.ident.to_string();
+
+            match measure_rows(measure) {
+                Ok(None) => parse_quote!({
+                    crate::native_stats::measure(#name, crate::native_stats::Rows::rows, move || #body)
+                }),
+                Ok(Some(arg)) => parse_quote!({
+                    let __explorer_rows = crate::native_stats::Rows::rows(&#arg);
+                    crate::native_stats::measure(#name, move |_| __explorer_rows, move || #body)
+                }),
+                Err(error) => return error.to_compile_error().into(),
+            }
+        }
+    };
+
+    declare(&args, &fun)
+}
+
+fn declare(args: &[Meta], fun: &ItemFn) -> TokenStream {
+    if args.is_empty() {
+        quote!(#[rustler::nif] #fun).into()
+    } else {
+        quote!(#[rustler::nif(#(#args),*)] #fun).into()
+    }
+}
+
+// Returns the argument given as `measure(rows = arg)`, if any.
+fn measure_rows(measure: &Meta) -> syn::Result<Option<syn::Expr>> {
+    match measure {
+        Meta::Path(_) => Ok(None),
+        Meta::List(list) => {
+            let rows: MetaNameValue = list.parse_args()?;
+
+            if rows.path.is_ident("rows") {
+                Ok(Some(rows.value))
+            } else {
+                Err(syn::Error::new_spanned(rows.path, "expected `rows = arg`"))
+            }
+        }
+        Meta::NameValue(_) => Err(syn::Error::new_spanned(
+            measure,
+            "expected `measure` or `measure(rows = arg)`",
+        )),
+    }
+}
+
+fn returns_explorer_result(fun: &ItemFn) -> bool {
+    let syn::ReturnType::Type(_, ty) = &fun.sig.output else {
+        return false;
+    };
+
+    let syn::Type::Path(path) = ty.as_ref() else {
+        return false;
+    };
+
+    let Some(segment) = path.path.segments.last() else {
+        return false;
+    };
+
+    let PathArguments::AngleBracketed(generics) = &segment.arguments else {
+        return false;
+    };
+
+    segment.ident == "Result"
+        && matches!(
+            generics.args.iter().nth(1),
+            Some(GenericArgument::Type(syn::Type::Path(error)))
+                if error.path.is_ident("ExplorerError")
+        )
+}
@@ -0,0 +1,127 @@
+use crate::ExplorerError;
+use explorer_macros::nif;
+use polars::prelude::*;
+use polars_expr::state::ExecutionState;
+use polars_mem_engine::{create_physical_plan, Executor};
+use rustler::{Resource, ResourceArc};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+// A token can be given to the NIFs that collect lazy frames, so another
+// process can cancel the query while it runs. Polars only checks for
+// cancellation between operations, so it may take a moment to stop.
+//
+// The same token may be shared by many queries, so it keeps the stop
+// flag of each query that is currently running.
+pub struct ExCancelTokenRef {
+    cancelled: AtomicBool,
+    queries: Mutex<Vec<Arc<AtomicBool>>>,
+}
+
+#[rustler::resource_impl]
+impl Resource for ExCancelTokenRef {}
+
+#[nif]
+pub fn cancel_token_new() -> ResourceArc<ExCancelTokenRef> {
+    ExCancelTokenRef {
+        cancelled: AtomicBool::new(false),
+        queries: Mutex::new(Vec::new()),
+    }
+    .into()
+}
+
+// Cancelling is permanent: queries started later with the same token
+// are cancelled right away.
+#[nif]
+pub fn cancel(token: ResourceArc<ExCancelTokenRef>) {
+    let queries = lock_queries(&token);
+    token.cancelled.store(true, Ordering::SeqCst);
+
+    for stop in queries.iter() {
+        stop.store(true, Ordering::SeqCst);
+    }
+}
+
+// Removes the stop flag of a query from its token once the query is done,
+// including when it panics.
+struct Registration<'a> {
+    token: &'a ExCancelTokenRef,
+    stop: Arc<AtomicBool>,
+}
+
+impl Drop for Registration<'_> {
+    fn drop(&mut self) {
+        lock_queries(self.token).retain(|stop| !Arc::ptr_eq(stop, &self.stop));
+    }
+}
+
+fn lock_queries(token: &ExCancelTokenRef) -> std::sync::MutexGuard<'_, Vec<Arc<AtomicBool>>> {
+    // The lock is never held while running user code, so a poisoned lock
+    // still holds a consistent list.
+    token
+        .queries
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn register(
+    token: &ExCancelTokenRef,
+    stop: Arc<AtomicBool>,
+) -> Result<Registration<'_>, ExplorerError> {
+    let mut queries = lock_queries(token);
+
+    if token.cancelled.load(Ordering::SeqCst) {
+        return Err(ExplorerError::Cancelled);
+    }
+
+    queries.push(stop.clone());
+
+    Ok(Registration { token, stop })
+}
+
+// Collects the lazy frame on the calling thread, so panics reach the
+// `catch_panic` of the NIF. This is what `LazyFrame::collect` does, except
+// that the stop flag of the execution state is registered with the token.
+// `LazyFrame::collect_concurrently`, the public API to cancel queries, is
+// not used because it runs the query as a task in the Polars pool, where
+// a panic aborts the VM. Building the plan by hand needs `polars-expr` and
+// `polars-mem-engine`, which must stay on the same version as `polars`.
+//
+// The streaming engine runs its pipelines with an execution state of its
+// own, which the token cannot stop. So queries with a token always run on
+// the in-memory engine, even if streaming was enabled, as it is for cloud
+// parquet scans.
+pub fn collect(
+    lf: LazyFrame,
+    token: Option<ResourceArc<ExCancelTokenRef>>,
+) -> Result<DataFrame, ExplorerError> {
+    let Some(token) = token else {
+        return Ok(lf.collect()?);
+    };
+
+    let mut plan = lf.with_streaming(false).to_alp_optimized()?;
+    let mut executor = create_physical_plan(plan.lp_top, &mut plan.lp_arena, &plan.expr_arena)?;
+    let mut state = ExecutionState::new();
+    let stop = state.cancel_token();
+    let _registration = register(&token, stop.clone())?;
+
+    match executor.execute(&mut state) {
+        Ok(df) => Ok(df),
+        Err(_) if stop.load(Ordering::SeqCst) => Err(ExplorerError::Cancelled),
+        Err(error) => Err(error.into()),
+    }
+}
+
+// Streaming sinks run on their own and have no way to be stopped.
+pub fn ensure_cancellable(
+    streaming: bool,
+    token: &Option<ResourceArc<ExCancelTokenRef>>,
+) -> Result<(), ExplorerError> {
+    if streaming && token.is_some() {
+        return Err(ExplorerError::Other(
+            "a cancel token cannot be used with streaming writes".into(),
+        ));
+    }
+
+    Ok(())
+}
@@ -1,13 +1,20 @@
+use explorer_macros::nif;
 use polars::prelude::*;
 use polars_ops::pivot::{pivot_stable, PivotAgg};
 
 use polars::export::{arrow, arrow::ffi};
 use std::collections::HashMap;
 
-use crate::datatypes::ExSeriesDtype;
+use crate::dataframe::io::schema_from_dtypes_pairs;
+use crate::datatypes::{ExCorrelationMethod, ExPivotAgg, ExSeriesDtype, ExUniqueKeepStrategy};
+use crate::encoding;
 use crate::ex_expr_to_exprs;
-use crate::{ExDataFrame, ExExpr, ExLazyFrame, ExSeries, ExplorerError};
+use crate::expressions::element;
+use crate::local_message::send_tagged;
+use crate::series::sample;
+use crate::{atoms, ExDataFrame, ExExpr, ExLazyFrame, ExSeries, ExplorerError};
 use either::Either;
+use rustler::{Encoder, Env, LocalPid, Term};
 
 // Loads the IO functions for read/writing CSV, NDJSON, Parquet, etc.
 pub mod io;
@@ -16,18 +23,30 @@ fn to_string_names(names: Vec<&str>) -> Vec<String> {
     names.into_iter().map(|s| s.to_string()).collect()
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+// New column names either come from a list or from an existing column,
+// which is then left out of the transposed values.
+#[nif(schedule = "DirtyCpu", measure)]
 pub fn df_transpose(
     df: ExDataFrame,
     keep_names_as: Option<&str>,
     new_col_names: Option<Vec<String>>,
+    names_from: Option<String>,
 ) -> Result<ExDataFrame, ExplorerError> {
-    let column_names = new_col_names.map(Either::Right);
+    let column_names = match (new_col_names, names_from) {
+        (Some(_), Some(_)) => {
+            return Err(ExplorerError::Other(
+                "cannot give both new column names and a column to take names from".into(),
+            ))
+        }
+        (Some(names), None) => Some(Either::Right(names)),
+        (None, Some(column)) => Some(Either::Left(column)),
+        (None, None) => None,
+    };
     let new_df = df.clone_inner().transpose(keep_names_as, column_names)?;
     Ok(ExDataFrame::new(new_df))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn df_names(df: ExDataFrame) -> Result<Vec<String>, ExplorerError> {
     let names = df
         .get_column_names()
@@ -37,7 +56,7 @@ pub fn df_names(df: ExDataFrame) -> Result<Vec<String>, ExplorerError> {
     Ok(names)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn df_dtypes(df: ExDataFrame) -> Result<Vec<ExSeriesDtype>, ExplorerError> {
     let mut dtypes: Vec<ExSeriesDtype> = vec![];
 
@@ -48,27 +67,168 @@ pub fn df_dtypes(df: ExDataFrame) -> Result<Vec<ExSeriesDtype>, ExplorerError> {
     Ok(dtypes)
 }
 
-#[rustler::nif]
+// Returns the name, dtype and first values of every column in one call,
+// which is what inspecting a dataframe needs.
+#[nif(schedule = "DirtyCpu")]
+pub fn df_glimpse(
+    env: Env,
+    df: ExDataFrame,
+    max_items_per_column: usize,
+) -> Result<Vec<(String, ExSeriesDtype, Term)>, ExplorerError> {
+    df.get_columns()
+        .iter()
+        .map(|column| {
+            let series = column
+                .as_materialized_series()
+                .head(Some(max_items_per_column));
+            let dtype = ExSeriesDtype::try_from(series.dtype())?;
+            let values = encoding::list_from_series(ExSeries::new(series), env)?;
+
+            Ok((column.name().to_string(), dtype, values))
+        })
+        .collect()
+}
+
+#[nif]
 pub fn df_shape(df: ExDataFrame) -> Result<(usize, usize), ExplorerError> {
     Ok(df.shape())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn df_n_rows(df: ExDataFrame) -> Result<usize, ExplorerError> {
     Ok(df.height())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn df_width(df: ExDataFrame) -> Result<usize, ExplorerError> {
     Ok(df.width())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn df_estimated_size(df: ExDataFrame) -> Result<usize, ExplorerError> {
     Ok(df.estimated_size())
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+// Returns the chunk lengths of every column, so the number of chunks
+// is the length of each list. Many small chunks slow down most kernels.
+#[nif]
+pub fn df_chunk_info(df: ExDataFrame) -> Result<Vec<(String, Vec<usize>)>, ExplorerError> {
+    let info = df
+        .get_columns()
+        .iter()
+        .map(|column| {
+            let lengths = column.as_materialized_series().chunk_lengths().collect();
+            (column.name().to_string(), lengths)
+        })
+        .collect();
+
+    Ok(info)
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn df_rechunk(df: ExDataFrame) -> Result<ExDataFrame, ExplorerError> {
+    let mut new_df = df.clone_inner();
+    new_df.as_single_chunk_par();
+
+    Ok(ExDataFrame::new(new_df))
+}
+
+// Appends the chunks of `other` to the columns of `target`. Existing data is
+// shared rather than copied, so this is cheap even for large frames, at the
+// cost of more chunks. Call `df_rechunk` once enough chunks have piled up.
+#[nif(schedule = "DirtyCpu")]
+pub fn df_extend(target: ExDataFrame, other: ExDataFrame) -> Result<ExDataFrame, ExplorerError> {
+    let mut new_df = target.clone_inner();
+    ensure_same_dtypes(&new_df, &other)?;
+    new_df.vstack_mut(&other)?;
+
+    Ok(ExDataFrame::new(new_df))
+}
+
+// Rows in `target` whose keys appear in `updates` take their values from
+// `updates` and keep their position. Rows in `updates` with new keys are
+// appended at the end. Keys must be unique within `updates`.
+#[nif(schedule = "DirtyCpu", measure)]
+pub fn df_upsert(
+    target: ExDataFrame,
+    updates: ExDataFrame,
+    on: Vec<String>,
+) -> Result<ExDataFrame, ExplorerError> {
+    let matched = "__explorer_upsert_matched__";
+    let suffix = "__explorer_upsert_update__";
+
+    let names: Vec<String> = target
+        .get_column_names()
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+
+    if updates.width() != names.len() || names.iter().any(|name| updates.column(name).is_err()) {
+        return Err(ExplorerError::Other(
+            "upsert expects both dataframes to have the same columns".into(),
+        ));
+    }
+
+    if updates.select(on.iter().cloned())?.is_duplicated()?.any() {
+        return Err(ExplorerError::Other(
+            "upsert expects the keys to be unique within the updates".into(),
+        ));
+    }
+
+    let keys: Vec<Expr> = on.iter().map(|key| col(key.as_str())).collect();
+    let marked = |df: &DataFrame| df.clone().lazy().with_column(lit(true).alias(matched));
+
+    let updated = target
+        .clone_inner()
+        .lazy()
+        .join_builder()
+        .with(marked(&updates))
+        .how(JoinType::Left)
+        .left_on(keys.clone())
+        .right_on(keys.clone())
+        .suffix(suffix)
+        .finish()
+        .select(
+            names
+                .iter()
+                .map(|name| {
+                    if on.contains(name) {
+                        col(name.as_str())
+                    } else {
+                        when(col(matched).is_not_null())
+                            .then(col(format!("{name}{suffix}")))
+                            .otherwise(col(name.as_str()))
+                            .alias(name.as_str())
+                    }
+                })
+                .collect::<Vec<Expr>>(),
+        );
+
+    // Updates whose keys are not in the target, kept in their original order.
+    let inserted = updates
+        .clone_inner()
+        .lazy()
+        .join_builder()
+        .with(marked(&target))
+        .how(JoinType::Left)
+        .left_on(keys.clone())
+        .right_on(keys)
+        .suffix(suffix)
+        .finish()
+        .filter(col(matched).is_null())
+        .select(
+            names
+                .iter()
+                .map(|name| col(name.as_str()))
+                .collect::<Vec<Expr>>(),
+        );
+
+    let new_df = concat([updated, inserted], UnionArgs::default())?.collect()?;
+
+    Ok(ExDataFrame::new(new_df))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn df_concat_columns(dfs: Vec<ExDataFrame>) -> Result<ExDataFrame, ExplorerError> {
     let mut previous_names = PlHashSet::new();
 
@@ -102,13 +262,13 @@ pub fn df_concat_columns(dfs: Vec<ExDataFrame>) -> Result<ExDataFrame, ExplorerE
     Ok(ExDataFrame::new(out_df))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn df_drop(df: ExDataFrame, name: &str) -> Result<ExDataFrame, ExplorerError> {
     let new_df = df.drop(name)?;
     Ok(ExDataFrame::new(new_df))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn df_select_at_idx(df: ExDataFrame, idx: usize) -> Result<Option<ExSeries>, ExplorerError> {
     let result = df
         .select_at_idx(idx)
@@ -116,7 +276,7 @@ pub fn df_select_at_idx(df: ExDataFrame, idx: usize) -> Result<Option<ExSeries>,
     Ok(result)
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn df_pull(df: ExDataFrame, name: &str) -> Result<ExSeries, ExplorerError> {
     let series = df
         .column(name)
@@ -124,7 +284,7 @@ pub fn df_pull(df: ExDataFrame, name: &str) -> Result<ExSeries, ExplorerError> {
     Ok(series)
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn df_mask(df: ExDataFrame, mask: ExSeries) -> Result<ExDataFrame, ExplorerError> {
     if let Ok(ca) = mask.bool() {
         let new_df = df.filter(ca)?;
@@ -134,7 +294,7 @@ pub fn df_mask(df: ExDataFrame, mask: ExSeries) -> Result<ExDataFrame, ExplorerE
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn df_slice_by_indices(
     df: ExDataFrame,
     indices: Vec<u32>,
@@ -149,7 +309,7 @@ pub fn df_slice_by_indices(
     Ok(ExDataFrame::new(new_df))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn df_slice_by_series(
     df: ExDataFrame,
     series: ExSeries,
@@ -173,7 +333,7 @@ pub fn df_slice_by_series(
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn df_sample_n(
     df: ExDataFrame,
     n: u64,
@@ -181,9 +341,20 @@ pub fn df_sample_n(
     shuffle: bool,
     seed: Option<u64>,
     groups: Vec<String>,
+    weights: Option<&str>,
+    stratified: bool,
 ) -> Result<ExDataFrame, ExplorerError> {
     let n_s = Series::new("n".into(), &[n]);
-    let new_df = if groups.is_empty() {
+    let new_df = if weights.is_some() || stratified {
+        let n = n as usize;
+        sample_by_indices(&df, groups, weights, replace, shuffle, seed, |sizes| {
+            if stratified {
+                sample::stratified_sizes(sizes, n)
+            } else {
+                vec![n; sizes.len()]
+            }
+        })?
+    } else if groups.is_empty() {
         df.sample_n(&n_s, replace, shuffle, seed)?
     } else {
         df.group_by_stable(groups)?
@@ -193,7 +364,7 @@ pub fn df_sample_n(
     Ok(ExDataFrame::new(new_df))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn df_sample_frac(
     df: ExDataFrame,
     frac: f64,
@@ -201,9 +372,23 @@ pub fn df_sample_frac(
     shuffle: bool,
     seed: Option<u64>,
     groups: Vec<String>,
+    weights: Option<&str>,
+    stratified: bool,
 ) -> Result<ExDataFrame, ExplorerError> {
     let frac_s = Series::new("frac".into(), &[frac]);
-    let new_df = if groups.is_empty() {
+    let new_df = if weights.is_some() || stratified {
+        sample_by_indices(&df, groups, weights, replace, shuffle, seed, |sizes| {
+            if stratified {
+                let total: usize = sizes.iter().sum();
+                sample::stratified_sizes(sizes, (frac * total as f64) as usize)
+            } else {
+                sizes
+                    .iter()
+                    .map(|size| (frac * *size as f64) as usize)
+                    .collect()
+            }
+        })?
+    } else if groups.is_empty() {
         df.sample_frac(&frac_s, replace, shuffle, seed)?
     } else {
         df.group_by_stable(groups)?
@@ -213,8 +398,83 @@ pub fn df_sample_frac(
     Ok(ExDataFrame::new(new_df))
 }
 
-#[rustler::nif]
-fn df_from_arrow_stream_pointer(stream_ptr: u64) -> Result<ExDataFrame, ExplorerError> {
+// Used for weighted and stratified sampling, which Polars does not support.
+// `sizes` receives the size of each group and returns how many rows to
+// sample from each one. Groups are sampled in order of first appearance.
+fn sample_by_indices<F>(
+    df: &DataFrame,
+    groups: Vec<String>,
+    weights: Option<&str>,
+    replace: bool,
+    shuffle: bool,
+    seed: Option<u64>,
+    sizes: F,
+) -> Result<DataFrame, ExplorerError>
+where
+    F: FnOnce(&[usize]) -> Vec<usize>,
+{
+    let group_indices: Vec<Vec<IdxSize>> = if groups.is_empty() {
+        vec![(0..df.height() as IdxSize).collect()]
+    } else {
+        df.group_by_stable(groups)?
+            .groups()?
+            .column("groups")?
+            .list()?
+            .into_no_null_iter()
+            .map(|series| Ok(series.idx()?.into_no_null_iter().collect()))
+            .collect::<PolarsResult<_>>()?
+    };
+
+    let weights = match weights {
+        Some(name) => Some(sample::weights_from_series(
+            df.column(name)?.as_materialized_series(),
+        )?),
+        None => None,
+    };
+
+    let lengths: Vec<usize> = group_indices.iter().map(Vec::len).collect();
+    let mut rng = sample::new_rng(seed);
+    let mut taken: Vec<IdxSize> = Vec::new();
+
+    for (group, n) in group_indices.iter().zip(sizes(&lengths)) {
+        let group_weights: Option<Vec<f64>> = weights
+            .as_ref()
+            .map(|weights| group.iter().map(|i| weights[*i as usize]).collect());
+
+        let sampled = sample::sample_indices(
+            group.len(),
+            n,
+            replace,
+            shuffle,
+            group_weights.as_deref(),
+            &mut rng,
+        )?;
+
+        taken.extend(sampled.into_iter().map(|i| group[i as usize]));
+    }
+
+    Ok(df.take(&IdxCa::from_vec("idx".into(), taken))?)
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn df_shuffle(df: ExDataFrame, seed: Option<u64>) -> Result<ExDataFrame, ExplorerError> {
+    let new_df = df.sample_n_literal(df.height(), false, true, seed)?;
+
+    Ok(ExDataFrame::new(new_df))
+}
+
+// Reads every batch of an Arrow C stream into a dataframe.
+//
+// When `dtypes` is not empty, each batch must have exactly those fields
+// and dtypes. When `acc` is given, the batches are appended to it, so a
+// producer can push its batches over several calls.
+#[nif]
+fn df_from_arrow_stream_pointer(
+    stream_ptr: u64,
+    dtypes: Vec<(&str, ExSeriesDtype)>,
+    acc: Option<ExDataFrame>,
+) -> Result<ExDataFrame, ExplorerError> {
+    let expected = schema_from_dtypes_pairs(dtypes)?;
     let stream_ptr = stream_ptr as *mut ffi::ArrowArrayStream;
     let stream_ref = unsafe { stream_ptr.as_mut() }
         .ok_or(ExplorerError::Other("Incorrect stream pointer".into()))?;
@@ -222,24 +482,61 @@ fn df_from_arrow_stream_pointer(stream_ptr: u64) -> Result<ExDataFrame, Explorer
     let mut res = unsafe { ffi::ArrowArrayStreamReader::try_new(stream_ref) }
         .map_err(arrow_to_explorer_error)?;
 
-    let df = match unsafe { res.next() } {
-        None => DataFrame::empty(),
-        Some(maybe) => {
-            let mut acc = array_to_dataframe(maybe)?;
+    let mut acc = acc.map(|acc| acc.clone_inner());
+
+    while let Some(maybe) = unsafe { res.next() } {
+        let df = array_to_dataframe(maybe)?;
+
+        if let Some(expected) = &expected {
+            validate_arrow_schema(&df, expected)?;
+        }
 
-            while let Some(maybe) = unsafe { res.next() } {
-                let df = array_to_dataframe(maybe)?;
+        match acc.as_mut() {
+            Some(acc) => {
+                ensure_same_dtypes(acc, &df)?;
                 acc.vstack_mut(&df)?;
             }
-
-            acc.align_chunks();
-            acc
+            None => acc = Some(df),
         }
-    };
+    }
+
+    let mut df = acc.unwrap_or_else(DataFrame::empty);
+    df.align_chunks();
 
     Ok(ExDataFrame::new(df))
 }
 
+// Errors name the offending field, and use the Polars error kinds so the
+// error class tells missing fields and mismatched dtypes apart.
+fn validate_arrow_schema(df: &DataFrame, expected: &Schema) -> Result<(), ExplorerError> {
+    for (name, dtype) in expected.iter() {
+        let column = df.column(name).map_err(
+            |_| polars_err!(ColumnNotFound: "field {:?} is missing from the Arrow stream", name),
+        )?;
+
+        if column.dtype() != dtype {
+            return Err(ExplorerError::SchemaMismatch {
+                column: name.to_string(),
+                expected: dtype.clone(),
+                actual: column.dtype().clone(),
+            });
+        }
+    }
+
+    if let Some(name) = df
+        .get_column_names()
+        .into_iter()
+        .find(|name| !expected.contains(name))
+    {
+        return Err(polars_err!(
+            SchemaMismatch: "unexpected field {:?} in the Arrow stream", name
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
 fn array_to_dataframe(
     stream_chunk: PolarsResult<Box<dyn arrow::array::Array>>,
 ) -> Result<DataFrame, ExplorerError> {
@@ -260,7 +557,7 @@ fn arrow_to_explorer_error(error: impl std::fmt::Debug) -> ExplorerError {
     ExplorerError::Other(format!("Internal Arrow error: #{error:?}"))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu", measure)]
 pub fn df_sort_by(
     df: ExDataFrame,
     by_columns: Vec<String>,
@@ -286,7 +583,7 @@ pub fn df_sort_by(
     Ok(ExDataFrame::new(new_df))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu", measure)]
 pub fn df_sort_with(
     data: ExDataFrame,
     expressions: Vec<ExExpr>,
@@ -295,6 +592,7 @@ pub fn df_sort_with(
     multithreaded: bool,
     nulls_last: bool,
     groups: Vec<String>,
+    limit: Option<IdxSize>,
 ) -> Result<ExDataFrame, ExplorerError> {
     let df = data.clone_inner();
     let exprs = ex_expr_to_exprs(expressions);
@@ -305,20 +603,28 @@ pub fn df_sort_with(
         .with_nulls_last(nulls_last)
         .with_order_descending_multi(directions);
 
+    // Slicing right after the sort lets Polars pick the top-k path
+    // instead of sorting every row.
+    let sort = |ldf: LazyFrame| {
+        let ldf = ldf.sort_by_exprs(&exprs, sort_options.clone());
+
+        match limit {
+            Some(limit) => ldf.limit(limit),
+            None => ldf,
+        }
+    };
+
     let new_df = if groups.is_empty() {
-        df.lazy().sort_by_exprs(exprs, sort_options).collect()?
+        sort(df.lazy()).collect()?
     } else {
-        df.group_by_stable(groups)?.apply(|df| {
-            df.lazy()
-                .sort_by_exprs(&exprs, sort_options.clone())
-                .collect()
-        })?
+        df.group_by_stable(groups)?
+            .apply(|df| sort(df.lazy()).collect())?
     };
 
     Ok(ExDataFrame::new(new_df))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn df_slice(
     df: ExDataFrame,
     offset: i64,
@@ -334,7 +640,56 @@ pub fn df_slice(
     Ok(ExDataFrame::new(new_df))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn df_upsample(
+    df: ExDataFrame,
+    time_column: &str,
+    every: &str,
+    groups: Vec<String>,
+) -> Result<ExDataFrame, ExplorerError> {
+    let every = Duration::try_parse(every)?;
+    let new_df = df.upsample_stable(groups, time_column, every)?;
+
+    Ok(ExDataFrame::new(new_df))
+}
+
+// Encodes the dataframe as rows in batches of `batch_size` and sends each
+// batch to `pid` as `{ref, {:chunk, rows}}`, followed by `{ref, :done}`,
+// where rows is a list of maps. Like `s_to_list_chunked`, only one batch
+// is encoded at a time.
+#[nif(schedule = "DirtyCpu")]
+pub fn df_to_rows_chunked(
+    df: ExDataFrame,
+    batch_size: usize,
+    atom_keys: bool,
+    pid: LocalPid,
+    reference: Term,
+) -> Result<(), ExplorerError> {
+    if batch_size == 0 {
+        return Err(ExplorerError::Other(
+            "to_rows_chunked expects a positive batch size".into(),
+        ));
+    }
+
+    send_tagged(pid, reference, |sender| {
+        let mut offset = 0;
+
+        while offset < df.height() {
+            let batch = df.slice(offset as i64, batch_size);
+
+            sender.send(|env| {
+                let rows = encoding::rows_from_dataframe(&batch, atom_keys, env)?;
+                Ok((atoms::chunk(), rows).encode(env))
+            })?;
+
+            offset += batch_size;
+        }
+
+        sender.send(|env| Ok(atoms::done().encode(env)))
+    })
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn df_to_dummies(df: ExDataFrame, selection: Vec<&str>) -> Result<ExDataFrame, ExplorerError> {
     let drop_first = false;
     let dummies = df
@@ -344,7 +699,44 @@ pub fn df_to_dummies(df: ExDataFrame, selection: Vec<&str>) -> Result<ExDataFram
     Ok(ExDataFrame::new(dummies))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+// An empty subset considers all columns. Rows keep their original order.
+#[nif(schedule = "DirtyCpu", measure)]
+pub fn df_unique(
+    df: ExDataFrame,
+    subset: Vec<String>,
+    keep: ExUniqueKeepStrategy,
+) -> Result<ExDataFrame, ExplorerError> {
+    let subset = if subset.is_empty() {
+        None
+    } else {
+        Some(subset)
+    };
+    let new_df = df.unique_stable(subset.as_deref(), keep.into(), None)?;
+
+    Ok(ExDataFrame::new(new_df))
+}
+
+// List columns are exploded in lock-step, so their lengths must match row by row.
+#[nif(schedule = "DirtyCpu")]
+pub fn df_explode(df: ExDataFrame, columns: Vec<&str>) -> Result<ExDataFrame, ExplorerError> {
+    let new_df = df.explode(columns)?;
+
+    Ok(ExDataFrame::new(new_df))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn df_unnest(
+    df: ExDataFrame,
+    columns: Vec<&str>,
+    separator: Option<&str>,
+) -> Result<ExDataFrame, ExplorerError> {
+    let new_df =
+        crate::lazyframe::unnest(df.clone_inner().lazy(), columns, separator)?.collect()?;
+
+    Ok(ExDataFrame::new(new_df))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn df_put_column(df: ExDataFrame, series: ExSeries) -> Result<ExDataFrame, ExplorerError> {
     let mut df = df.clone();
     let s = series.clone_inner();
@@ -353,13 +745,158 @@ pub fn df_put_column(df: ExDataFrame, series: ExSeries) -> Result<ExDataFrame, E
     Ok(ExDataFrame::new(new_df))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn df_with_row_index(
+    df: ExDataFrame,
+    name: &str,
+    offset: Option<IdxSize>,
+) -> Result<ExDataFrame, ExplorerError> {
+    let new_df = df.with_row_index(name.into(), offset)?;
+
+    Ok(ExDataFrame::new(new_df))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn df_nil_count(df: ExDataFrame) -> Result<ExDataFrame, ExplorerError> {
     let new_df = df.null_count();
     Ok(ExDataFrame::new(new_df))
 }
 
-#[rustler::nif]
+// All statistics are computed in a single lazy query, so Polars can
+// evaluate the columns in parallel. Each column becomes a list of its
+// statistics, which is then exploded into one row per metric.
+// Numeric columns are summarised as floats, others as strings.
+#[nif(schedule = "DirtyCpu", measure)]
+pub fn df_describe(df: ExDataFrame, percentiles: Vec<f64>) -> Result<ExDataFrame, ExplorerError> {
+    let df = df.clone_inner();
+
+    if df.width() == 0 {
+        return Err(ExplorerError::Other(
+            "cannot describe a DataFrame without any columns".into(),
+        ));
+    }
+
+    let mut metrics: Vec<String> = ["count", "nil_count", "mean", "std", "min"]
+        .iter()
+        .map(|metric| metric.to_string())
+        .collect();
+    metrics.extend(
+        percentiles
+            .iter()
+            .map(|p| format!("{}%", (p * 100.0).trunc())),
+    );
+    metrics.push("max".to_string());
+
+    let exprs = df
+        .get_columns()
+        .iter()
+        .map(|column| {
+            let stats = describe_column_exprs(column, &percentiles);
+            concat_list(stats).map(|expr| expr.alias(column.name().clone()))
+        })
+        .collect::<PolarsResult<Vec<Expr>>>()?;
+
+    let mut new_df = df.lazy().select(exprs).explode([col("*")]).collect()?;
+    new_df.insert_column(0, Series::new("describe".into(), metrics))?;
+
+    Ok(ExDataFrame::new(new_df))
+}
+
+fn describe_column_exprs(column: &Column, percentiles: &[f64]) -> Vec<Expr> {
+    let dtype = column.dtype();
+    let c = col(column.name().clone());
+    let strategy = crate::parse_quantile_interpol_options("nearest");
+
+    if dtype.is_numeric() {
+        let mut stats = vec![
+            c.clone().count(),
+            c.clone().null_count(),
+            c.clone().mean(),
+            c.clone().std(1),
+            c.clone().min(),
+        ];
+        stats.extend(
+            percentiles
+                .iter()
+                .map(|p| c.clone().quantile(lit(*p), strategy)),
+        );
+        stats.push(c.max());
+
+        stats
+            .into_iter()
+            .map(|stat| stat.cast(DataType::Float64))
+            .collect()
+    } else {
+        let null = || lit(NULL).cast(DataType::String);
+
+        let mut stats = vec![
+            c.clone().count().cast(DataType::String),
+            c.clone().null_count().cast(DataType::String),
+            null(),
+            null(),
+        ];
+
+        match describe_format(dtype) {
+            Some(format) => {
+                stats.push(c.clone().min().dt().to_string(&format));
+                stats.extend(percentiles.iter().map(|_| null()));
+                stats.push(c.max().dt().to_string(&format));
+            }
+            None => stats.extend((0..percentiles.len() + 2).map(|_| null())),
+        }
+
+        stats
+    }
+}
+
+// Temporal min and max are formatted the same way `to_string/1` formats
+// their Elixir values. Durations use the Polars format, which it mirrors.
+fn describe_format(dtype: &DataType) -> Option<String> {
+    let fraction = |time_unit: &TimeUnit| match time_unit {
+        TimeUnit::Milliseconds => "%.3f",
+        TimeUnit::Microseconds | TimeUnit::Nanoseconds => "%.6f",
+    };
+
+    match dtype {
+        DataType::Date => Some("%Y-%m-%d".into()),
+        DataType::Time => Some("%H:%M:%S%.6f".into()),
+        DataType::Datetime(time_unit, None) => {
+            Some(format!("%Y-%m-%d %H:%M:%S{}", fraction(time_unit)))
+        }
+        DataType::Datetime(time_unit, Some(time_zone)) if time_zone == "Etc/UTC" => {
+            Some(format!("%Y-%m-%d %H:%M:%S{}Z", fraction(time_unit)))
+        }
+        DataType::Datetime(time_unit, Some(time_zone)) => Some(format!(
+            "%Y-%m-%d %H:%M:%S{}%:z %Z {time_zone}",
+            fraction(time_unit)
+        )),
+        DataType::Duration(_) => Some("polars".into()),
+        _ => None,
+    }
+}
+
+// Hashes each row across the given columns, or all of them when empty.
+// Hashes are stable for the same seed and data within a build, but they
+// may differ across Polars versions and CPU architectures.
+#[nif(schedule = "DirtyCpu")]
+pub fn df_hash_rows(
+    df: ExDataFrame,
+    seed: u64,
+    columns: Vec<String>,
+) -> Result<ExSeries, ExplorerError> {
+    let mut df = if columns.is_empty() {
+        df.clone_inner()
+    } else {
+        df.select(columns)?
+    };
+
+    let hasher = PlRandomState::with_seeds(seed, seed, seed, seed);
+    let hashes = df.hash_rows(Some(hasher))?.with_name("hash".into());
+
+    Ok(ExSeries::new(hashes.into_series()))
+}
+
+#[nif]
 pub fn df_from_series(columns: Vec<ExSeries>) -> Result<ExDataFrame, ExplorerError> {
     let columns = columns
         .into_iter()
@@ -371,14 +908,14 @@ pub fn df_from_series(columns: Vec<ExSeries>) -> Result<ExDataFrame, ExplorerErr
     Ok(ExDataFrame::new(df))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn df_groups(df: ExDataFrame, groups: Vec<&str>) -> Result<ExDataFrame, ExplorerError> {
     let groups = df.group_by(groups)?.groups()?;
 
     Ok(ExDataFrame::new(groups))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn df_group_indices(
     df: ExDataFrame,
     groups: Vec<&str>,
@@ -394,13 +931,136 @@ pub fn df_group_indices(
     Ok(series)
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+// Sends every group to `pid` as `{ref, {:group, df}}`, in the order they
+// first appear, followed by `{ref, :done}`. The process applies its own
+// function to each group and concatenates the results with `df_concat_rows`.
+#[nif(schedule = "DirtyCpu")]
+pub fn df_group_by_apply(
+    df: ExDataFrame,
+    keys: Vec<String>,
+    pid: LocalPid,
+    reference: Term,
+) -> Result<(), ExplorerError> {
+    let groups = df.partition_by_stable(keys, true)?;
+
+    send_tagged(pid, reference, |sender| {
+        for group in groups {
+            sender.send(|env| Ok((atoms::group(), ExDataFrame::new(group)).encode(env)))?;
+        }
+
+        sender.send(|env| Ok(atoms::done().encode(env)))
+    })
+}
+
+// All frames must have the same columns, in the same order.
+#[nif(schedule = "DirtyCpu", measure)]
+pub fn df_concat_rows(dfs: Vec<ExDataFrame>) -> Result<ExDataFrame, ExplorerError> {
+    let mut dfs = dfs.into_iter();
+
+    let new_df = match dfs.next() {
+        Some(first) => {
+            let mut acc = first.clone_inner();
+
+            for df in dfs {
+                ensure_same_dtypes(&acc, &df)?;
+                acc.vstack_mut(&df)?;
+            }
+
+            acc.align_chunks();
+            acc
+        }
+        None => DataFrame::empty(),
+    };
+
+    Ok(ExDataFrame::new(new_df))
+}
+
+// Polars doesn't tell which column has an incompatible dtype when stacking
+// frames, so they are checked the same way first to report it.
+fn ensure_same_dtypes(target: &DataFrame, other: &DataFrame) -> Result<(), ExplorerError> {
+    let mismatch = target
+        .get_columns()
+        .iter()
+        .zip(other.get_columns())
+        .find(|(left, right)| {
+            left.name() == right.name() && right.dtype().matches_schema_type(left.dtype()).is_err()
+        });
+
+    match mismatch {
+        Some((left, right)) => Err(ExplorerError::SchemaMismatch {
+            column: left.name().to_string(),
+            expected: left.dtype().clone(),
+            actual: right.dtype().clone(),
+        }),
+        None => Ok(()),
+    }
+}
+
+// Groups are returned in the order they first appear in the dataframe.
+// With `as_map`, they are returned as a map keyed by their group value,
+// or by a tuple of group values when partitioning by several columns.
+#[nif(schedule = "DirtyCpu")]
+pub fn df_partition_by<'a>(
+    env: Env<'a>,
+    df: ExDataFrame,
+    columns: Vec<String>,
+    include_key: bool,
+    as_map: bool,
+) -> Result<Term<'a>, ExplorerError> {
+    if !as_map {
+        let dfs: Vec<ExDataFrame> = df
+            .partition_by_stable(columns, include_key)?
+            .into_iter()
+            .map(ExDataFrame::new)
+            .collect();
+
+        return Ok(dfs.encode(env));
+    }
+
+    let mut keys = Vec::new();
+    let mut dfs = Vec::new();
+
+    for group in df.partition_by_stable(&columns, true)? {
+        let values = columns
+            .iter()
+            .map(|name| {
+                let value = group.column(name)?.as_materialized_series().head(Some(1));
+                let (value, _) = encoding::list_from_series(ExSeries::new(value), env)?
+                    .list_get_cell()
+                    .map_err(|error| {
+                        ExplorerError::Internal(format!("cannot decode group value: {error:?}"))
+                    })?;
+
+                Ok(value)
+            })
+            .collect::<Result<Vec<Term>, ExplorerError>>()?;
+
+        keys.push(match values.as_slice() {
+            [value] => *value,
+            values => rustler::types::tuple::make_tuple(env, values),
+        });
+
+        let group = if include_key {
+            group
+        } else {
+            group.drop_many(&columns)
+        };
+
+        dfs.push(ExDataFrame::new(group).encode(env));
+    }
+
+    Term::map_from_term_arrays(env, &keys, &dfs)
+        .map_err(|error| ExplorerError::Internal(format!("cannot build groups map: {error:?}")))
+}
+
+#[nif(schedule = "DirtyCpu", measure)]
 pub fn df_pivot_wider(
     df: ExDataFrame,
     id_columns: Vec<&str>,
     pivot_column: &str,
     values_column: Vec<&str>,
     names_prefix: Option<&str>,
+    aggregation: ExPivotAgg,
 ) -> Result<ExDataFrame, ExplorerError> {
     // We need to preserve the original ID columns with a prefix,
     // so if there is any "new column name" coming from a "value column"
@@ -417,15 +1077,28 @@ pub fn df_pivot_wider(
         df.rename(id_name, new_name.into())?;
     }
 
-    let mut new_df = pivot_stable(
-        &df,
-        [pivot_column],
-        Some(temp_id_names),
-        Some(values_column),
-        false,
-        Some(PivotAgg::First),
-        None,
-    )?;
+    // Duplicated id/pivot pairs are combined with the given aggregation.
+    let mut new_df = match pivot_agg(aggregation) {
+        Some(agg) => pivot_stable(
+            &df,
+            [pivot_column],
+            Some(temp_id_names),
+            Some(values_column),
+            false,
+            Some(agg),
+            None,
+        )?,
+        // Polars has no built-in list aggregation, so we go through an expression.
+        None => polars::lazy::frame::pivot::pivot_stable(
+            &df,
+            [pivot_column],
+            Some(temp_id_names),
+            Some(values_column),
+            false,
+            Some(element()),
+            None,
+        )?,
+    };
 
     // Instead of using the names from the pivoted DF, we go back
     // and restore the original ID column names, so we can use our
@@ -475,13 +1148,162 @@ pub fn df_pivot_wider(
     Ok(ExDataFrame::new(new_df))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+fn pivot_agg(aggregation: ExPivotAgg) -> Option<PivotAgg> {
+    match aggregation {
+        ExPivotAgg::First => Some(PivotAgg::First),
+        ExPivotAgg::Last => Some(PivotAgg::Last),
+        ExPivotAgg::Sum => Some(PivotAgg::Sum),
+        ExPivotAgg::Mean => Some(PivotAgg::Mean),
+        ExPivotAgg::Median => Some(PivotAgg::Median),
+        ExPivotAgg::Min => Some(PivotAgg::Min),
+        ExPivotAgg::Max => Some(PivotAgg::Max),
+        ExPivotAgg::Count => Some(PivotAgg::Count),
+        ExPivotAgg::List => None,
+    }
+}
+
+#[nif(schedule = "DirtyCpu", measure)]
+pub fn df_correlation(
+    df: ExDataFrame,
+    columns: Vec<String>,
+    method: ExCorrelationMethod,
+    column_name: &str,
+) -> Result<ExDataFrame, ExplorerError> {
+    let new_df = pairwise_frame(&df, columns, column_name, |left, right| match method {
+        ExCorrelationMethod::Pearson => pearson_corr(left, right),
+        ExCorrelationMethod::Spearman => spearman_rank_corr(left, right, true),
+    })?;
+
+    Ok(ExDataFrame::new(new_df))
+}
+
+#[nif(schedule = "DirtyCpu", measure)]
+pub fn df_covariance(
+    df: ExDataFrame,
+    columns: Vec<String>,
+    ddof: u8,
+    column_name: &str,
+) -> Result<ExDataFrame, ExplorerError> {
+    let new_df = pairwise_frame(&df, columns, column_name, |left, right| {
+        cov(left, right, ddof)
+    })?;
+
+    Ok(ExDataFrame::new(new_df))
+}
+
+// Returns one row per numeric column with its min, max, mean, sum and
+// null count. All statistics are computed in a single lazy query and
+// returned as floats, except for the null count.
+#[nif(schedule = "DirtyCpu", measure)]
+pub fn df_column_stats(df: ExDataFrame) -> Result<ExDataFrame, ExplorerError> {
+    let names: Vec<String> = df
+        .get_columns()
+        .iter()
+        .filter(|column| column.dtype().is_numeric())
+        .map(|column| column.name().to_string())
+        .collect();
+
+    let mut exprs = Vec::with_capacity(names.len() * 5);
+
+    for name in &names {
+        let c = col(name.as_str());
+        let stats = [
+            c.clone().min(),
+            c.clone().max(),
+            c.clone().mean(),
+            c.clone().sum(),
+            c.null_count(),
+        ];
+
+        for stat in stats {
+            exprs.push(stat.alias(format!("{}", exprs.len())));
+        }
+    }
+
+    let values = collect_floats(&df, exprs)?;
+    let stat = |offset: usize| -> Vec<Option<f64>> {
+        values.iter().skip(offset).step_by(5).copied().collect()
+    };
+    let null_counts: Vec<Option<u64>> = stat(4)
+        .into_iter()
+        .map(|count| count.map(|count| count as u64))
+        .collect();
+
+    let new_df = DataFrame::new(vec![
+        Column::new("column".into(), &names),
+        Column::new("min".into(), stat(0)),
+        Column::new("max".into(), stat(1)),
+        Column::new("mean".into(), stat(2)),
+        Column::new("sum".into(), stat(3)),
+        Column::new("null_count".into(), null_counts),
+    ])?;
+
+    Ok(ExDataFrame::new(new_df))
+}
+
+// Computes `stat` for every pair of columns in a single lazy query.
+// The result has a column with the names of the columns, followed
+// by one float column per name, so it reads as a matrix.
+fn pairwise_frame<F>(
+    df: &DataFrame,
+    columns: Vec<String>,
+    column_name: &str,
+    stat: F,
+) -> Result<DataFrame, ExplorerError>
+where
+    F: Fn(Expr, Expr) -> Expr,
+{
+    let size = columns.len();
+    let mut exprs = Vec::with_capacity(size * size);
+
+    for left in &columns {
+        for right in &columns {
+            let left = col(left.as_str()).cast(DataType::Float64);
+            let right = col(right.as_str()).cast(DataType::Float64);
+            exprs.push(stat(left, right).alias(format!("{}", exprs.len())));
+        }
+    }
+
+    let values = collect_floats(df, exprs)?;
+    let mut new_columns = vec![Column::new(column_name.into(), &columns)];
+
+    for (i, name) in columns.iter().enumerate() {
+        let row = &values[i * size..(i + 1) * size];
+        new_columns.push(Column::new(name.into(), row));
+    }
+
+    Ok(DataFrame::new(new_columns)?)
+}
+
+// Evaluates aggregations in a single lazy query, returning each one as a float.
+fn collect_floats(df: &DataFrame, exprs: Vec<Expr>) -> Result<Vec<Option<f64>>, ExplorerError> {
+    if exprs.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let values = df
+        .clone()
+        .lazy()
+        .select(exprs)
+        .collect()?
+        .get_columns()
+        .iter()
+        .map(|column| {
+            let column = column.cast(&DataType::Float64)?;
+            Ok(column.as_materialized_series().f64()?.get(0))
+        })
+        .collect::<PolarsResult<_>>()?;
+
+    Ok(values)
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn df_lazy(df: ExDataFrame) -> Result<ExLazyFrame, ExplorerError> {
     let new_lf = df.clone_inner().lazy();
     Ok(ExLazyFrame::new(new_lf))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn df_re_dtype(pattern: &str) -> Result<ExSeriesDtype, ExplorerError> {
     let s = Series::new("dummy".into(), [""])
         .into_frame()
@@ -8,22 +8,82 @@
 //
 // Today we have the following formats: CSV, NDJSON, Parquet, Apache Arrow and Apache Arrow Stream.
 //
+use explorer_macros::nif;
+use polars::frame::chunk_df_for_writing;
 use polars::prelude::*;
 use std::num::NonZeroUsize;
 
-use rustler::{Binary, Env, NewBinary};
+use rustler::{Binary, Encoder, Env, LocalPid, NewBinary, Term};
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Cursor};
 
 use crate::datatypes::{ExParquetCompression, ExQuoteStyle, ExS3Entry, ExSeriesDtype};
-use crate::{ExDataFrame, ExplorerError};
+use crate::local_message::with_progress;
+use crate::{atoms, ExDataFrame, ExplorerError};
 
 #[cfg(feature = "cloud")]
 use crate::cloud_writer::CloudWriter;
 
+// CSV files are written in batches of this many rows, so progress can be
+// reported after each one.
+const CSV_BATCH_ROWS: usize = 512 * 512;
+
+// The default size of Parquet row groups in Polars. Each row group is
+// written as a batch, so the file is the same whether progress is reported
+// or not.
+const PARQUET_ROW_GROUP_ROWS: usize = 512 * 512;
+
+// Writes the dataframe as CSV, reporting the rows written after each batch.
+// An empty dataframe is still written once, so the headers are kept.
+pub fn write_csv<W: std::io::Write>(
+    df: &DataFrame,
+    writer: CsvWriter<W>,
+    report: &mut dyn FnMut(usize) -> Result<(), ExplorerError>,
+) -> Result<(), ExplorerError> {
+    let mut batched = writer.batched(&df.schema())?;
+    let mut written = 0;
+
+    loop {
+        let mut batch = df.slice(written as i64, CSV_BATCH_ROWS);
+        batch.align_chunks();
+        batched.write_batch(&batch)?;
+        written += batch.height();
+        report(written)?;
+
+        if written >= df.height() {
+            break;
+        }
+    }
+
+    batched.finish()?;
+    Ok(())
+}
+
+// Writes the dataframe as Parquet, reporting the rows written after each
+// row group. Row groups are split like `ParquetWriter::finish` does.
+pub fn write_parquet<W: std::io::Write>(
+    df: &DataFrame,
+    writer: ParquetWriter<W>,
+    report: &mut dyn FnMut(usize) -> Result<(), ExplorerError>,
+) -> Result<(), ExplorerError> {
+    let mut df = df.clone();
+    let mut chunked = chunk_df_for_writing(&mut df, PARQUET_ROW_GROUP_ROWS)?.into_owned();
+    let mut batched = writer.batched(&chunked.schema())?;
+    let mut written = 0;
+
+    for row_group in chunked.split_chunks() {
+        batched.write_batch(&row_group)?;
+        written += row_group.height();
+        report(written)?;
+    }
+
+    batched.finish()?;
+    Ok(())
+}
+
 // ============ CSV ============ //
 
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", measure)]
 #[allow(clippy::too_many_arguments)]
 pub fn df_from_csv(
     filename: &str,
@@ -94,26 +154,27 @@ pub fn schema_from_dtypes_pairs(
     Ok(Some(Arc::new(schema)))
 }
 
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", measure(rows = data))]
 pub fn df_to_csv(
     data: ExDataFrame,
     filename: &str,
     include_headers: bool,
     delimiter: u8,
     quote_style: ExQuoteStyle,
+    progress: Option<(LocalPid, Term)>,
 ) -> Result<(), ExplorerError> {
     let file = File::create(filename)?;
     let mut buf_writer = BufWriter::new(file);
-    CsvWriter::new(&mut buf_writer)
+    let writer = CsvWriter::new(&mut buf_writer)
         .include_header(include_headers)
         .with_separator(delimiter)
-        .with_quote_style(quote_style.into())
-        .finish(&mut data.clone())?;
-    Ok(())
+        .with_quote_style(quote_style.into());
+
+    with_progress(progress, |report| write_csv(&data, writer, report))
 }
 
 #[cfg(feature = "aws")]
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo")]
 pub fn df_to_csv_cloud(
     data: ExDataFrame,
     ex_entry: ExS3Entry,
@@ -134,7 +195,7 @@ pub fn df_to_csv_cloud(
     Ok(())
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu", measure(rows = data))]
 pub fn df_dump_csv(
     env: Env,
     data: ExDataFrame,
@@ -156,7 +217,7 @@ pub fn df_dump_csv(
     Ok(values_binary.into())
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu", measure)]
 #[allow(clippy::too_many_arguments)]
 pub fn df_load_csv(
     binary: Binary,
@@ -215,45 +276,79 @@ pub fn df_load_csv(
 
 // ============ Parquet ============ //
 
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", measure)]
 pub fn df_from_parquet(
     filename: &str,
     stop_after_n_rows: Option<usize>,
     column_names: Option<Vec<String>>,
     projection: Option<Vec<usize>>,
     rechunk: bool,
+    progress: Option<(LocalPid, Term)>,
 ) -> Result<ExDataFrame, ExplorerError> {
     let file = File::open(filename)?;
-    let buf_reader = BufReader::new(file);
+    let reader = |slice: Option<(usize, usize)>| {
+        ParquetReader::new(BufReader::new(&file))
+            .with_slice(slice)
+            .with_columns(column_names.clone())
+            .with_projection(projection.clone())
+            .set_rechunk(rechunk)
+    };
 
-    let reader = ParquetReader::new(buf_reader)
-        .with_slice(stop_after_n_rows.map(|max| (0, max)))
-        .with_columns(column_names)
-        .with_projection(projection)
-        .set_rechunk(rechunk);
+    let df = match progress {
+        None => reader(stop_after_n_rows.map(|max| (0, max))).finish()?,
+        // Row groups are read one at a time, so progress can be reported
+        // after each one. Categoricals of different row groups can only be
+        // stacked if they share the string cache.
+        progress => with_progress(progress, |report| {
+            let _cache = StringCacheHolder::hold();
+            let mut empty = reader(Some((0, 0)));
+            let metadata = empty.get_metadata()?.clone();
+            let max_rows = stop_after_n_rows.unwrap_or(usize::MAX);
+            let mut df = empty.finish()?;
+
+            for row_group in &metadata.row_groups {
+                let read = df.height();
+
+                if read >= max_rows {
+                    break;
+                }
+
+                let slice = (read, row_group.num_rows().min(max_rows - read));
+                let mut row_group_reader = reader(Some(slice));
+                row_group_reader.set_metadata(metadata.clone());
+                df.vstack_mut(&row_group_reader.finish()?)?;
+                report(df.height())?;
+            }
+
+            if rechunk {
+                df.as_single_chunk_par();
+            }
+
+            Ok(df)
+        })?,
+    };
 
-    Ok(ExDataFrame::new(reader.finish()?))
+    Ok(ExDataFrame::new(df))
 }
 
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", measure(rows = data))]
 pub fn df_to_parquet(
     data: ExDataFrame,
     filename: &str,
     ex_compression: ExParquetCompression,
+    progress: Option<(LocalPid, Term)>,
 ) -> Result<(), ExplorerError> {
     let file = File::create(filename)?;
     let mut buf_writer = BufWriter::new(file);
 
     let compression = ParquetCompression::try_from(ex_compression)?;
+    let writer = ParquetWriter::new(&mut buf_writer).with_compression(compression);
 
-    ParquetWriter::new(&mut buf_writer)
-        .with_compression(compression)
-        .finish(&mut data.clone())?;
-    Ok(())
+    with_progress(progress, |report| write_parquet(&data, writer, report))
 }
 
 #[cfg(feature = "aws")]
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo")]
 pub fn df_to_parquet_cloud(
     data: ExDataFrame,
     ex_entry: ExS3Entry,
@@ -309,7 +404,7 @@ fn build_aws_s3_cloud_writer(ex_entry: ExS3Entry) -> Result<CloudWriter, Explore
     CloudWriter::new(object_store, ex_entry.key.into())
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu", measure(rows = data))]
 pub fn df_dump_parquet(
     env: Env,
     data: ExDataFrame,
@@ -329,7 +424,7 @@ pub fn df_dump_parquet(
     Ok(values_binary.into())
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu", measure)]
 pub fn df_load_parquet(binary: Binary) -> Result<ExDataFrame, ExplorerError> {
     let cursor = Cursor::new(binary.as_slice());
     let reader = ParquetReader::new(cursor);
@@ -339,7 +434,7 @@ pub fn df_load_parquet(binary: Binary) -> Result<ExDataFrame, ExplorerError> {
 
 // ============ IPC ============ //
 
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", measure)]
 pub fn df_from_ipc(
     filename: &str,
     columns: Option<Vec<String>>,
@@ -354,7 +449,7 @@ pub fn df_from_ipc(
     Ok(ExDataFrame::new(reader.finish()?))
 }
 
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", measure(rows = data))]
 pub fn df_to_ipc(
     data: ExDataFrame,
     filename: &str,
@@ -374,7 +469,7 @@ pub fn df_to_ipc(
 }
 
 #[cfg(feature = "aws")]
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo")]
 pub fn df_to_ipc_cloud(
     data: ExDataFrame,
     ex_entry: ExS3Entry,
@@ -396,7 +491,7 @@ pub fn df_to_ipc_cloud(
     Ok(())
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu", measure(rows = data))]
 pub fn df_dump_ipc<'a>(
     env: Env<'a>,
     data: ExDataFrame,
@@ -419,7 +514,7 @@ pub fn df_dump_ipc<'a>(
     Ok(values_binary.into())
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu", measure)]
 pub fn df_load_ipc(
     binary: Binary,
     columns: Option<Vec<String>>,
@@ -445,7 +540,7 @@ fn decode_ipc_compression(compression: &str) -> Result<IpcCompression, ExplorerE
 
 // ============ IPC Streaming ============ //
 
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", measure)]
 pub fn df_from_ipc_stream(
     filename: &str,
     columns: Option<Vec<String>>,
@@ -460,7 +555,7 @@ pub fn df_from_ipc_stream(
     Ok(ExDataFrame::new(reader.finish()?))
 }
 
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", measure(rows = data))]
 pub fn df_to_ipc_stream(
     data: ExDataFrame,
     filename: &str,
@@ -479,7 +574,7 @@ pub fn df_to_ipc_stream(
 }
 
 #[cfg(feature = "aws")]
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo")]
 pub fn df_to_ipc_stream_cloud(
     data: ExDataFrame,
     ex_entry: ExS3Entry,
@@ -501,7 +596,7 @@ pub fn df_to_ipc_stream_cloud(
     Ok(())
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu", measure(rows = data))]
 pub fn df_dump_ipc_stream<'a>(
     env: Env<'a>,
     data: ExDataFrame,
@@ -524,7 +619,7 @@ pub fn df_dump_ipc_stream<'a>(
     Ok(values_binary.into())
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu", measure)]
 pub fn df_load_ipc_stream(
     binary: Binary,
     columns: Option<Vec<String>>,
@@ -541,7 +636,7 @@ pub fn df_load_ipc_stream(
 // ============ NDJSON ============ //
 
 #[cfg(feature = "ndjson")]
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", measure)]
 pub fn df_from_ndjson(
     filename: &str,
     infer_schema_length: Option<usize>,
@@ -561,7 +656,7 @@ pub fn df_from_ndjson(
 }
 
 #[cfg(feature = "ndjson")]
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", measure(rows = data))]
 pub fn df_to_ndjson(data: ExDataFrame, filename: &str) -> Result<(), ExplorerError> {
     let file = File::create(filename)?;
     let mut buf_writer = BufWriter::new(file);
@@ -573,7 +668,7 @@ pub fn df_to_ndjson(data: ExDataFrame, filename: &str) -> Result<(), ExplorerErr
 }
 
 #[cfg(all(feature = "ndjson", feature = "aws"))]
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo")]
 pub fn df_to_ndjson_cloud(data: ExDataFrame, ex_entry: ExS3Entry) -> Result<(), ExplorerError> {
     let mut cloud_writer = build_aws_s3_cloud_writer(ex_entry)?;
 
@@ -587,7 +682,7 @@ pub fn df_to_ndjson_cloud(data: ExDataFrame, ex_entry: ExS3Entry) -> Result<(),
 }
 
 #[cfg(feature = "ndjson")]
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu", measure(rows = data))]
 pub fn df_dump_ndjson(env: Env, data: ExDataFrame) -> Result<Binary, ExplorerError> {
     let mut buf = vec![];
 
@@ -602,7 +697,7 @@ pub fn df_dump_ndjson(env: Env, data: ExDataFrame) -> Result<Binary, ExplorerErr
 }
 
 #[cfg(feature = "ndjson")]
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu", measure)]
 pub fn df_load_ndjson(
     binary: Binary,
     infer_schema_length: Option<usize>,
@@ -623,7 +718,7 @@ pub fn df_load_ndjson(
 // ============ For when the feature is not enabled ============ //
 
 #[cfg(not(feature = "ndjson"))]
-#[rustler::nif]
+#[nif]
 pub fn df_from_ndjson(
     _filename: &str,
     _infer_schema_length: Option<usize>,
@@ -635,7 +730,7 @@ pub fn df_from_ndjson(
 }
 
 #[cfg(not(feature = "ndjson"))]
-#[rustler::nif]
+#[nif]
 pub fn df_to_ndjson(_data: ExDataFrame, _filename: &str) -> Result<(), ExplorerError> {
     Err(ExplorerError::Other("Explorer was compiled without the \"ndjson\" feature enabled. \
         This is mostly due to this feature being incompatible with your computer's architecture. \
@@ -643,7 +738,7 @@ pub fn df_to_ndjson(_data: ExDataFrame, _filename: &str) -> Result<(), ExplorerE
 }
 
 #[cfg(not(feature = "ndjson"))]
-#[rustler::nif]
+#[nif]
 pub fn df_dump_ndjson(_data: ExDataFrame) -> Result<Binary<'static>, ExplorerError> {
     Err(ExplorerError::Other("Explorer was compiled without the \"ndjson\" feature enabled. \
         This is mostly due to this feature being incompatible with your computer's architecture. \
@@ -651,7 +746,7 @@ pub fn df_dump_ndjson(_data: ExDataFrame) -> Result<Binary<'static>, ExplorerErr
 }
 
 #[cfg(not(feature = "ndjson"))]
-#[rustler::nif]
+#[nif]
 pub fn df_load_ndjson(
     _binary: Binary,
     _infer_schema_length: Option<usize>,
@@ -663,7 +758,7 @@ pub fn df_load_ndjson(
 }
 
 #[cfg(not(feature = "aws"))]
-#[rustler::nif]
+#[nif]
 pub fn df_to_parquet_cloud(
     _data: ExDataFrame,
     _ex_entry: ExS3Entry,
@@ -675,7 +770,7 @@ pub fn df_to_parquet_cloud(
 }
 
 #[cfg(not(feature = "aws"))]
-#[rustler::nif]
+#[nif]
 pub fn df_to_csv_cloud(
     _data: ExDataFrame,
     _ex_entry: ExS3Entry,
@@ -689,7 +784,7 @@ pub fn df_to_csv_cloud(
 }
 
 #[cfg(not(feature = "aws"))]
-#[rustler::nif]
+#[nif]
 pub fn df_to_ipc_cloud(
     _data: ExDataFrame,
     _ex_entry: ExS3Entry,
@@ -701,7 +796,7 @@ pub fn df_to_ipc_cloud(
 }
 
 #[cfg(not(feature = "aws"))]
-#[rustler::nif]
+#[nif]
 pub fn df_to_ipc_stream_cloud(
     _data: ExDataFrame,
     _ex_entry: ExS3Entry,
@@ -713,7 +808,7 @@ pub fn df_to_ipc_stream_cloud(
 }
 
 #[cfg(not(any(feature = "ndjson", feature = "aws")))]
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo")]
 pub fn df_to_ndjson_cloud(_data: ExDataFrame, _ex_entry: ExS3Entry) -> Result<(), ExplorerError> {
     Err(ExplorerError::Other("Explorer was compiled without the \"aws\" and \"ndjson\" features enabled. \
         This is mostly due to these feature being incompatible with your computer's architecture. \
@@ -1,6 +1,7 @@
 mod ex_dtypes;
 
 use crate::atoms;
+use crate::encoding;
 use crate::ExplorerError;
 use chrono::prelude::*;
 
@@ -20,6 +21,7 @@ use polars::prelude::cloud::AmazonS3ConfigKey as S3Key;
 use chrono_tz::{OffsetComponents, OffsetName, Tz};
 
 pub use polars::export::arrow::datatypes::TimeUnit as ArrowTimeUnit;
+use polars::export::arrow::legacy::kernels::NonExistent;
 
 pub use polars::export::arrow::temporal_conversions::{
     date32_to_date as days_to_date, timestamp_ms_to_datetime as timestamp_ms_to_naive_datetime,
@@ -123,6 +125,8 @@ impl ExDataFrame {
     }
 
     // Returns a clone of the DataFrame inside the ResourceArc container.
+    // This is cheap: columns are reference counted, so only the vector of
+    // column handles is copied, and Polars copies the buffers on write.
     pub fn clone_inner(&self) -> DataFrame {
         self.resource.0.clone()
     }
@@ -189,6 +193,7 @@ impl ExSeries {
     }
 
     // Returns a clone of the Series inside the ResourceArc container.
+    // Like with dataframes, this only bumps the reference count of the data.
     pub fn clone_inner(&self) -> Series {
         self.resource.0.clone()
     }
@@ -398,9 +403,16 @@ pub fn ex_naive_datetime_to_timestamp(
     }
 }
 
-impl From<i64> for ExNaiveDateTime {
-    fn from(timestamp: i64) -> Self {
-        timestamp_to_naive_datetime(timestamp, TimeUnit::Microseconds).into()
+impl ExNaiveDateTime {
+    // Timestamps are only meaningful together with their time unit,
+    // which may be milli, micro or nanoseconds depending on the column.
+    pub fn from_timestamp(timestamp: i64, time_unit: TimeUnit) -> Self {
+        let ndt = timestamp_to_naive_datetime(timestamp, time_unit);
+
+        ExNaiveDateTime {
+            microsecond: ndt.microsecond_tuple_tu(time_unit),
+            ..ndt.into()
+        }
     }
 }
 
@@ -467,7 +479,7 @@ pub fn ex_datetime_to_timestamp(
     ex_datetime: ExDateTime,
     time_unit: TimeUnit,
 ) -> Result<i64, ExplorerError> {
-    let datetime = DateTime::from(ex_datetime);
+    let datetime = ex_datetime.to_utc();
 
     match time_unit {
         TimeUnit::Milliseconds => Ok(datetime.timestamp_millis()),
@@ -482,6 +494,17 @@ pub fn ex_datetime_to_timestamp(
     }
 }
 
+impl ExDateTime<'_> {
+    // Elixir already carries the offsets that were in effect for the wall
+    // clock, so we use them instead of resolving the zone again. This keeps
+    // ambiguous and skipped local times intact, and it also works for zones
+    // that are unknown to our time zone database.
+    pub fn to_utc(self) -> DateTime<Utc> {
+        let offset = chrono::Duration::seconds(self.utc_offset + self.std_offset);
+        NaiveDateTime::from(self).and_utc() - offset
+    }
+}
+
 impl<'a> From<&'a DateTime<Tz>> for ExDateTime<'a> {
     fn from(dt_tz: &'a DateTime<Tz>) -> ExDateTime<'a> {
         let time_zone = dt_tz.offset().tz_id();
@@ -504,24 +527,12 @@ impl<'a> From<&'a DateTime<Tz>> for ExDateTime<'a> {
     }
 }
 
-impl From<ExDateTime<'_>> for DateTime<Tz> {
-    fn from(ex_dt: ExDateTime<'_>) -> DateTime<Tz> {
-        let time_zone = ex_dt.time_zone.parse::<Tz>().unwrap();
+impl TryFrom<ExDateTime<'_>> for DateTime<Tz> {
+    type Error = ExplorerError;
 
-        // Best approach I could find to avoid warning:
-        // https://github.com/chronotope/chrono/issues/873#issuecomment-1333716953
-        let dt_tz_without_micro = time_zone
-            .with_ymd_and_hms(
-                ex_dt.year,
-                ex_dt.month,
-                ex_dt.day,
-                ex_dt.hour,
-                ex_dt.minute,
-                ex_dt.second,
-            )
-            .unwrap();
-        let micro = chrono::Duration::microseconds(ex_dt.microsecond.0.into());
-        dt_tz_without_micro + micro
+    fn try_from(ex_dt: ExDateTime<'_>) -> Result<DateTime<Tz>, ExplorerError> {
+        let time_zone = encoding::parse_time_zone(ex_dt.time_zone)?;
+        Ok(ex_dt.to_utc().with_timezone(&time_zone))
     }
 }
 
@@ -536,11 +547,11 @@ impl From<ExDateTime<'_>> for NaiveDateTime {
 
 impl Literal for ExDateTime<'_> {
     fn lit(self) -> Expr {
-        let ndt = NaiveDateTime::from(self);
         let time_zone = self.time_zone.to_string();
 
+        // Zoned datetimes are stored as UTC timestamps in Polars.
         Expr::Literal(LiteralValue::DateTime(
-            ndt.and_utc().timestamp_micros(),
+            self.to_utc().timestamp_micros(),
             TimeUnit::Microseconds,
             Some(time_zone.into()),
         ))
@@ -739,6 +750,16 @@ pub enum ExCorrelationMethod {
     Spearman,
 }
 
+// Epoch timestamps may also be counted in seconds, which is not a unit
+// Polars stores temporal values in.
+#[derive(NifTaggedEnum)]
+pub enum ExTimestampUnit {
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
 #[derive(NifTaggedEnum)]
 pub enum ExRankMethod {
     Average,
@@ -749,6 +770,93 @@ pub enum ExRankMethod {
     Random,
 }
 
+#[derive(NifTaggedEnum)]
+pub enum ExClosedWindow {
+    Left,
+    Right,
+    Both,
+    None,
+}
+
+impl From<ExClosedWindow> for ClosedWindow {
+    fn from(value: ExClosedWindow) -> Self {
+        match value {
+            ExClosedWindow::Left => ClosedWindow::Left,
+            ExClosedWindow::Right => ClosedWindow::Right,
+            ExClosedWindow::Both => ClosedWindow::Both,
+            ExClosedWindow::None => ClosedWindow::None,
+        }
+    }
+}
+
+#[derive(NifTaggedEnum)]
+pub enum ExNonExistent {
+    Null,
+    Raise,
+}
+
+impl From<ExNonExistent> for NonExistent {
+    fn from(value: ExNonExistent) -> Self {
+        match value {
+            ExNonExistent::Null => NonExistent::Null,
+            ExNonExistent::Raise => NonExistent::Raise,
+        }
+    }
+}
+
+#[derive(NifTaggedEnum)]
+pub enum ExCategoricalOrdering {
+    Physical,
+    Lexical,
+}
+
+impl From<ExCategoricalOrdering> for CategoricalOrdering {
+    fn from(value: ExCategoricalOrdering) -> Self {
+        match value {
+            ExCategoricalOrdering::Physical => CategoricalOrdering::Physical,
+            ExCategoricalOrdering::Lexical => CategoricalOrdering::Lexical,
+        }
+    }
+}
+
+#[derive(NifTaggedEnum)]
+pub enum ExFrequenciesSort {
+    Counts,
+    Values,
+}
+
+#[derive(NifTaggedEnum)]
+pub enum ExUniqueKeepStrategy {
+    First,
+    Last,
+    Any,
+    None,
+}
+
+impl From<ExUniqueKeepStrategy> for UniqueKeepStrategy {
+    fn from(value: ExUniqueKeepStrategy) -> Self {
+        match value {
+            ExUniqueKeepStrategy::First => UniqueKeepStrategy::First,
+            ExUniqueKeepStrategy::Last => UniqueKeepStrategy::Last,
+            ExUniqueKeepStrategy::Any => UniqueKeepStrategy::Any,
+            ExUniqueKeepStrategy::None => UniqueKeepStrategy::None,
+        }
+    }
+}
+
+#[derive(NifTaggedEnum)]
+pub enum ExPivotAgg {
+    First,
+    Last,
+    Sum,
+    Mean,
+    Median,
+    Min,
+    Max,
+    Count,
+    List,
+}
+
 impl TryFrom<ExParquetCompression> for ParquetCompression {
     type Error = ExplorerError;
 
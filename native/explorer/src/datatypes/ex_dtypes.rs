@@ -1,8 +1,10 @@
 use crate::ExplorerError;
+use polars::datatypes::create_enum_dtype;
 use polars::datatypes::CategoricalOrdering;
 use polars::datatypes::DataType;
 use polars::datatypes::Field;
 use polars::datatypes::TimeUnit;
+use polars::export::arrow::array::Utf8ViewArray;
 use rustler::NifTaggedEnum;
 
 #[derive(NifTaggedEnum)]
@@ -40,6 +42,8 @@ pub enum ExSeriesDtype {
     Binary,
     Boolean,
     Category,
+    // The list of categories, in order.
+    Enum(Vec<String>),
     Date,
     F(u8),
     S(u8),
@@ -64,6 +68,13 @@ impl TryFrom<&DataType> for ExSeriesDtype {
             DataType::Binary => Ok(ExSeriesDtype::Binary),
             DataType::Boolean => Ok(ExSeriesDtype::Boolean),
             DataType::Categorical(_, _) => Ok(ExSeriesDtype::Category),
+            DataType::Enum(Some(mapping), _) => Ok(ExSeriesDtype::Enum(
+                mapping
+                    .get_categories()
+                    .values_iter()
+                    .map(|category| category.to_string())
+                    .collect(),
+            )),
             DataType::Date => Ok(ExSeriesDtype::Date),
             DataType::Float64 => Ok(ExSeriesDtype::F(64)),
             DataType::Float32 => Ok(ExSeriesDtype::F(32)),
@@ -71,6 +82,7 @@ impl TryFrom<&DataType> for ExSeriesDtype {
             DataType::Int16 => Ok(ExSeriesDtype::S(16)),
             DataType::Int32 => Ok(ExSeriesDtype::S(32)),
             DataType::Int64 => Ok(ExSeriesDtype::S(64)),
+            DataType::Int128 => Ok(ExSeriesDtype::S(128)),
 
             DataType::UInt8 => Ok(ExSeriesDtype::U(8)),
             DataType::UInt16 => Ok(ExSeriesDtype::U(16)),
@@ -119,6 +131,9 @@ impl TryFrom<&ExSeriesDtype> for DataType {
             ExSeriesDtype::Category => {
                 Ok(DataType::Categorical(None, CategoricalOrdering::default()))
             }
+            ExSeriesDtype::Enum(categories) => Ok(create_enum_dtype(
+                Utf8ViewArray::from_slice_values(categories),
+            )),
             ExSeriesDtype::Date => Ok(DataType::Date),
             ExSeriesDtype::F(64) => Ok(DataType::Float64),
             ExSeriesDtype::F(32) => Ok(DataType::Float32),
@@ -129,6 +144,7 @@ impl TryFrom<&ExSeriesDtype> for DataType {
             ExSeriesDtype::S(16) => Ok(DataType::Int16),
             ExSeriesDtype::S(32) => Ok(DataType::Int32),
             ExSeriesDtype::S(64) => Ok(DataType::Int64),
+            ExSeriesDtype::S(128) => Ok(DataType::Int128),
             ExSeriesDtype::S(size) => Err(ExplorerError::Other(format!(
                 "signed integer dtype of size {size} is not valid"
             ))),
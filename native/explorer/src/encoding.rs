@@ -1,6 +1,6 @@
 use chrono::prelude::*;
 use polars::prelude::*;
-use rustler::{Encoder, Env, NewBinary, OwnedBinary, ResourceArc, Term};
+use rustler::{Atom, Encoder, Env, NewBinary, OwnedBinary, ResourceArc, Term};
 use std::collections::HashMap;
 use std::{mem, slice};
 
@@ -18,6 +18,7 @@ use crate::datatypes::{
 };
 use crate::ExplorerError;
 
+use polars::export::arrow::array::Array;
 use rustler::types::atom;
 use rustler::wrapper::{list, map, NIF_TERM};
 
@@ -276,6 +277,12 @@ fn datetime_struct_keys(env: Env) -> [NIF_TERM; 13] {
     ]
 }
 
+pub fn parse_time_zone(time_zone: &str) -> Result<Tz, ExplorerError> {
+    time_zone
+        .parse::<Tz>()
+        .map_err(|_| ExplorerError::TimestampConversion(format!("unknown time zone: {time_zone}")))
+}
+
 #[inline]
 pub fn encode_datetime(
     timestamp: i64,
@@ -308,7 +315,7 @@ fn datetime_series_to_list<'b>(s: &Series, env: Env<'b>) -> Result<Term<'b>, Exp
         _ => panic!("datetime_series_to_list called on series with wrong type"),
     };
     let time_zone = match s.dtype() {
-        DataType::Datetime(_, Some(time_zone)) => time_zone.parse::<Tz>().unwrap(),
+        DataType::Datetime(_, Some(time_zone)) => parse_time_zone(time_zone)?,
         _ => panic!("datetime_series_to_list called on series with wrong type"),
     };
 
@@ -684,6 +691,141 @@ fn null_series_to_list<'b>(s: &Series, env: Env<'b>) -> Result<Term<'b>, Explore
     Ok(unsafe { Term::new(env, list) })
 }
 
+// Nested series are encoded by converting all of their inner values at once
+// and then slicing the resulting terms, instead of dispatching on every value.
+fn series_to_terms(s: Series, env: Env) -> Result<Vec<Term>, ExplorerError> {
+    list_from_series(ExSeries::new(s), env)?
+        .decode::<Vec<Term>>()
+        .map_err(|error| ExplorerError::Internal(format!("cannot collect terms: {error:?}")))
+}
+
+fn list_series_to_list<'b>(s: &Series, env: Env<'b>) -> Result<Term<'b>, ExplorerError> {
+    let ca = s.list()?.rechunk();
+    let values = &series_to_terms(ca.get_inner(), env)?;
+    let nil = atom::nil().to_term(env);
+
+    Ok(unsafe_iterator_series_to_list!(
+        env,
+        ca.downcast_iter().flat_map(|array| {
+            let offsets = array.offsets();
+
+            (0..array.len()).map(move |index| {
+                if array.is_valid(index) {
+                    let (start, end) = offsets.start_end(index);
+                    values[start..end].encode(env)
+                } else {
+                    nil
+                }
+            })
+        })
+    ))
+}
+
+fn struct_series_to_list<'b>(s: &Series, env: Env<'b>) -> Result<Term<'b>, ExplorerError> {
+    struct_series_to_maps(s, false, env)
+}
+
+// Encodes each struct as a map keyed by the field names. The keys are
+// atoms when `atom_keys` is true, including the keys of nested structs.
+pub fn struct_series_to_maps<'b>(
+    s: &Series,
+    atom_keys: bool,
+    env: Env<'b>,
+) -> Result<Term<'b>, ExplorerError> {
+    let ca = s.struct_()?;
+    let fields = ca.fields_as_series();
+    let keys = fields
+        .iter()
+        .map(|field| encode_key(field.name(), atom_keys, env))
+        .collect::<Result<Vec<NIF_TERM>, ExplorerError>>()?;
+    let columns = fields
+        .into_iter()
+        .map(|field| match field.dtype() {
+            DataType::Struct(_) if atom_keys => struct_series_to_maps(&field, atom_keys, env)?
+                .decode::<Vec<Term>>()
+                .map_err(|error| {
+                    ExplorerError::Internal(format!("cannot collect terms: {error:?}"))
+                }),
+            _ => series_to_terms(field, env),
+        })
+        .collect::<Result<Vec<_>, ExplorerError>>()?;
+    let validity: Vec<bool> = ca
+        .is_not_null()
+        .into_iter()
+        .map(|valid| valid.unwrap_or(false))
+        .collect();
+    let nil = atom::nil().to_term(env);
+
+    Ok(unsafe_iterator_series_to_list!(
+        env,
+        validity.iter().enumerate().map(|(row, valid)| {
+            if *valid {
+                let values: Vec<NIF_TERM> = columns
+                    .iter()
+                    .map(|column| column[row].as_c_arg())
+                    .collect();
+
+                unsafe {
+                    Term::new(
+                        env,
+                        map::make_map_from_arrays(env.as_c_arg(), &keys, &values).unwrap(),
+                    )
+                }
+            } else {
+                nil
+            }
+        })
+    ))
+}
+
+fn encode_key(name: &str, atom_keys: bool, env: Env) -> Result<NIF_TERM, ExplorerError> {
+    let key = if atom_keys {
+        Atom::from_str(env, name)
+            .map_err(|error| ExplorerError::Internal(format!("cannot encode key: {error:?}")))?
+            .to_term(env)
+    } else {
+        name.encode(env)
+    };
+
+    Ok(key.as_c_arg())
+}
+
+// Encodes the dataframe as a list of maps, one per row, keyed by the
+// column names. The keys are atoms when `atom_keys` is true.
+pub fn rows_from_dataframe<'b>(
+    df: &DataFrame,
+    atom_keys: bool,
+    env: Env<'b>,
+) -> Result<Term<'b>, ExplorerError> {
+    let keys = df
+        .get_column_names()
+        .iter()
+        .map(|name| encode_key(name, atom_keys, env))
+        .collect::<Result<Vec<NIF_TERM>, ExplorerError>>()?;
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|column| series_to_terms(column.as_materialized_series().clone(), env))
+        .collect::<Result<Vec<_>, ExplorerError>>()?;
+
+    Ok(unsafe_iterator_series_to_list!(
+        env,
+        (0..df.height()).map(|row| {
+            let values: Vec<NIF_TERM> = columns
+                .iter()
+                .map(|column| column[row].as_c_arg())
+                .collect();
+
+            unsafe {
+                Term::new(
+                    env,
+                    map::make_map_from_arrays(env.as_c_arg(), &keys, &values).unwrap(),
+                )
+            }
+        })
+    ))
+}
+
 macro_rules! series_to_iovec {
     ($resource:ident, $s:ident, $env:ident, $convert_function:ident, $in_type:ty) => {{
         Ok(unsafe_iterator_series_to_list!(
@@ -722,6 +864,7 @@ pub fn resource_term_from_value<'b>(
         AnyValue::Int16(v) => Ok(v.encode(env)),
         AnyValue::Int32(v) => Ok(v.encode(env)),
         AnyValue::Int64(v) => Ok(v.encode(env)),
+        AnyValue::Int128(v) => Ok(v.encode(env)),
         AnyValue::UInt8(v) => Ok(v.encode(env)),
         AnyValue::UInt16(v) => Ok(v.encode(env)),
         AnyValue::UInt32(v) => Ok(v.encode(env)),
@@ -732,10 +875,12 @@ pub fn resource_term_from_value<'b>(
         AnyValue::Time(v) => encode_time(v, env),
         AnyValue::Datetime(v, time_unit, None) => encode_naive_datetime(v, time_unit, env),
         AnyValue::Datetime(v, time_unit, Some(time_zone)) => {
-            encode_datetime(v, time_unit, time_zone.parse::<Tz>().unwrap(), env)
+            encode_datetime(v, time_unit, parse_time_zone(time_zone)?, env)
         }
         AnyValue::Duration(v, time_unit) => encode_duration(v, time_unit, env),
-        AnyValue::Categorical(idx, mapping, _) => Ok(mapping.get(idx).encode(env)),
+        AnyValue::Categorical(idx, mapping, _) | AnyValue::Enum(idx, mapping, _) => {
+            Ok(mapping.get(idx).encode(env))
+        }
         AnyValue::List(series) => list_from_series(ExSeries::new(series), env),
         AnyValue::Struct(_, _, fields) => v
             ._iter_struct_av()
@@ -782,6 +927,7 @@ pub fn list_from_series(s: ExSeries, env: Env) -> Result<Term, ExplorerError> {
         DataType::Int16 => series_to_list!(s, env, i16),
         DataType::Int32 => series_to_list!(s, env, i32),
         DataType::Int64 => series_to_list!(s, env, i64),
+        DataType::Int128 => series_to_list!(s, env, i128),
 
         DataType::UInt8 => series_to_list!(s, env, u8),
         DataType::UInt16 => series_to_list!(s, env, u16),
@@ -799,22 +945,12 @@ pub fn list_from_series(s: ExSeries, env: Env) -> Result<Term, ExplorerError> {
 
         DataType::Binary => generic_binary_series_to_list(&s.resource, &s, env),
         DataType::String => generic_string_series_to_list(&s, env),
-        DataType::Categorical(Some(mapping), _) => categorical_series_to_list(&s, env, mapping),
-
-        DataType::List(_inner_dtype) => s
-            .list()?
-            .into_iter()
-            .map(|item| match item {
-                Some(list) => list_from_series(ExSeries::new(list), env),
-                None => Ok(None::<bool>.encode(env)),
-            })
-            .collect::<Result<Vec<Term>, ExplorerError>>()
-            .map(|lists| lists.encode(env)),
-        DataType::Struct(_fields) => s
-            .iter()
-            .map(|value| resource_term_from_value(&s.resource, value, env))
-            .collect::<Result<Vec<_>, ExplorerError>>()
-            .map(|values| values.encode(env)),
+        DataType::Categorical(Some(mapping), _) | DataType::Enum(Some(mapping), _) => {
+            categorical_series_to_list(&s, env, mapping)
+        }
+
+        DataType::List(_inner_dtype) => list_series_to_list(&s, env),
+        DataType::Struct(_fields) => struct_series_to_list(&s, env),
         DataType::Decimal(_precision, _scale) => decimal_series_to_list(&s, env),
         dt => panic!("to_list/1 not implemented for {dt:?}"),
     }
@@ -825,11 +961,13 @@ pub fn iovec_from_series(s: ExSeries, env: Env) -> Result<Term, ExplorerError> {
     let resource = &s.resource;
 
     match s.dtype() {
+        // Arrow packs booleans into bitmaps, so they are the only
+        // dtype that needs to be copied, one byte per value.
         DataType::Boolean => {
             let mut bin = OwnedBinary::new(s.len()).unwrap();
             let slice = bin.as_mut_slice();
-            for (i, v) in s.bool()?.into_iter().enumerate() {
-                slice[i] = v.unwrap() as u8;
+            for (byte, v) in slice.iter_mut().zip(s.bool()?.into_no_null_iter()) {
+                *byte = v as u8;
             }
             Ok([bin.release(env)].encode(env))
         }
@@ -837,6 +975,7 @@ pub fn iovec_from_series(s: ExSeries, env: Env) -> Result<Term, ExplorerError> {
         DataType::Int16 => series_to_iovec!(resource, s, env, i16, i16),
         DataType::Int32 => series_to_iovec!(resource, s, env, i32, i32),
         DataType::Int64 => series_to_iovec!(resource, s, env, i64, i64),
+        DataType::Int128 => series_to_iovec!(resource, s, env, i128, i128),
         DataType::UInt8 => series_to_iovec!(resource, s, env, u8, u8),
         DataType::UInt16 => series_to_iovec!(resource, s, env, u16, u16),
         DataType::UInt32 => series_to_iovec!(resource, s, env, u32, u32),
@@ -845,13 +984,14 @@ pub fn iovec_from_series(s: ExSeries, env: Env) -> Result<Term, ExplorerError> {
         DataType::Float64 => series_to_iovec!(resource, s, env, f64, f64),
         DataType::Date => series_to_iovec!(resource, s, env, date, i32),
         DataType::Time => series_to_iovec!(resource, s, env, time, i64),
-        DataType::Datetime(_, None) => {
+        // The time zone does not change the physical values.
+        DataType::Datetime(_, _) => {
             series_to_iovec!(resource, s, env, datetime, i64)
         }
         DataType::Duration(_) => {
             series_to_iovec!(resource, s, env, duration, i64)
         }
-        DataType::Categorical(Some(_), _) => {
+        DataType::Categorical(Some(_), _) | DataType::Enum(Some(_), _) => {
             let cat_series = s.cast(&DataType::UInt32)?;
 
             series_to_iovec!(resource, cat_series, env, u32, u32)
@@ -1,5 +1,8 @@
-use rustler::{Encoder, Env, Term};
+use crate::datatypes::ExSeriesDtype;
+use polars::prelude::{DataType, PolarsError};
+use rustler::{Atom, Encoder, Env, Term};
 use std::io;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use thiserror::Error;
 
 // Defines the atoms for each value of ExplorerError.
@@ -9,9 +12,28 @@ rustler::atoms! {
     polars,
     internal,
     other,
+    timestamp_conversion,
     try_from_int,
     parquet,
-    unknown
+    unknown,
+    panic,
+    cancelled,
+    // Polars errors are classified by their own variant.
+    column_not_found,
+    compute,
+    duplicate,
+    invalid_operation,
+    no_data,
+    out_of_bounds,
+    field_not_found,
+    schema_mismatch,
+    shape_mismatch,
+    sql,
+    string_cache_mismatch,
+    // Metadata keys.
+    column,
+    expected,
+    actual
 }
 
 #[derive(Error, Debug)]
@@ -32,12 +54,122 @@ pub enum ExplorerError {
     TryFromInt(#[from] std::num::TryFromIntError),
     #[error(transparent)]
     Unknown(#[from] anyhow::Error),
+    #[error("Panic: {0}")]
+    Panic(String),
+    #[error("Query cancelled")]
+    Cancelled,
+    #[error("Schema Mismatch: expected column {column:?} to have dtype {expected}, got: {actual}")]
+    SchemaMismatch {
+        column: String,
+        expected: DataType,
+        actual: DataType,
+    },
 }
 
+impl ExplorerError {
+    // The class lets Elixir tell errors apart without parsing the message.
+    pub fn class(&self) -> Atom {
+        match self {
+            ExplorerError::Io(_) => io(),
+            ExplorerError::Utf8(_) => utf8(),
+            ExplorerError::Polars(error) => polars_class(error),
+            ExplorerError::Internal(_) => internal(),
+            ExplorerError::Other(_) => other(),
+            ExplorerError::TimestampConversion(_) => timestamp_conversion(),
+            ExplorerError::TryFromInt(_) => try_from_int(),
+            ExplorerError::Unknown(_) => unknown(),
+            ExplorerError::Panic(_) => panic(),
+            ExplorerError::Cancelled => cancelled(),
+            ExplorerError::SchemaMismatch { .. } => schema_mismatch(),
+        }
+    }
+
+    // Metadata lets Elixir recover from an error without parsing the
+    // message, such as the missing column or the mismatched dtypes.
+    fn metadata<'b>(&self, env: Env<'b>) -> Term<'b> {
+        let pairs = match self {
+            ExplorerError::SchemaMismatch {
+                column: name,
+                expected: expected_dtype,
+                actual: actual_dtype,
+            } => vec![
+                (column(), name.encode(env)),
+                (expected(), encode_dtype(expected_dtype, env)),
+                (actual(), encode_dtype(actual_dtype, env)),
+            ],
+            ExplorerError::Polars(error) => match missing_column(error) {
+                Some(name) => vec![(column(), name.encode(env))],
+                None => vec![],
+            },
+            _ => vec![],
+        };
+
+        Term::map_from_pairs(env, &pairs).unwrap()
+    }
+}
+
+// Polars names the missing column in the message, quoted with `{:?}`.
+fn missing_column(error: &PolarsError) -> Option<String> {
+    match error {
+        PolarsError::ColumnNotFound(message) => {
+            let (_, rest) = message.split_once('"')?;
+            let (name, _) = rest.split_once('"')?;
+            Some(name.to_string())
+        }
+        PolarsError::Context { error, .. } => missing_column(error),
+        _ => None,
+    }
+}
+
+// Dtypes are given as Explorer dtypes, or as strings for the ones Explorer
+// doesn't support.
+fn encode_dtype<'b>(dtype: &DataType, env: Env<'b>) -> Term<'b> {
+    match ExSeriesDtype::try_from(dtype) {
+        Ok(dtype) => dtype.encode(env),
+        Err(_) => dtype.to_string().encode(env),
+    }
+}
+
+fn polars_class(error: &PolarsError) -> Atom {
+    match error {
+        PolarsError::ColumnNotFound(_) => column_not_found(),
+        PolarsError::ComputeError(_) => compute(),
+        PolarsError::Duplicate(_) => duplicate(),
+        PolarsError::InvalidOperation(_) => invalid_operation(),
+        PolarsError::IO { .. } => io(),
+        PolarsError::NoData(_) => no_data(),
+        PolarsError::OutOfBounds(_) => out_of_bounds(),
+        PolarsError::SchemaFieldNotFound(_) | PolarsError::StructFieldNotFound(_) => {
+            field_not_found()
+        }
+        PolarsError::SchemaMismatch(_) => schema_mismatch(),
+        PolarsError::ShapeMismatch(_) => shape_mismatch(),
+        PolarsError::SQLInterface(_) | PolarsError::SQLSyntax(_) => sql(),
+        PolarsError::StringCacheMismatch(_) => string_cache_mismatch(),
+        PolarsError::Context { error, .. } => polars_class(error),
+    }
+}
+
+// Errors are encoded as `{class, message, metadata}`, where metadata is a
+// map that is empty unless the error has more to tell.
 impl Encoder for ExplorerError {
     fn encode<'b>(&self, env: Env<'b>) -> Term<'b> {
-        format!("{self}").encode(env)
+        (self.class(), format!("{self}"), self.metadata(env)).encode(env)
     }
 }
 
-impl std::panic::RefUnwindSafe for ExplorerError {}
+// Rustler turns a panic into an exception, but Polars may panic on input
+// that is merely invalid. NIFs declared with `explorer_macros::nif` run
+// their body through this, so the panic message is returned as a regular
+// error instead.
+pub fn catch_panic<T>(fun: impl FnOnce() -> Result<T, ExplorerError>) -> Result<T, ExplorerError> {
+    catch_unwind(AssertUnwindSafe(fun)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+
+        Err(ExplorerError::Panic(message))
+    })
+}
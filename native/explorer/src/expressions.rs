@@ -5,17 +5,24 @@
 // wrapped in an Elixir struct.
 
 use crate::datatypes::{
-    ExCorrelationMethod, ExDate, ExDateTime, ExDuration, ExNaiveDateTime, ExRankMethod,
-    ExSeriesDtype, ExValidValue,
+    ExCorrelationMethod, ExDate, ExDateTime, ExDuration, ExNaiveDateTime, ExNonExistent,
+    ExRankMethod, ExSeriesDtype, ExTimeUnit, ExTimestampUnit, ExValidValue,
 };
-use crate::series::{cast_str_to_f64, ewm_opts, rolling_opts_fixed_window};
-use crate::{ExDataFrame, ExExpr, ExSeries};
+use crate::series::log::expm1;
+use crate::series::similarity::{jaro_winkler_similarity, levenshtein_distance};
+use crate::series::{cast_str_to_f64, ewm_opts, index_of_expr, rolling_expr, strptime_many_expr};
+use crate::{ExDataFrame, ExExpr, ExSeries, ExplorerError};
+use explorer_macros::nif;
 use polars::lazy::dsl;
+use polars::lazy::dsl::ListNameSpaceExtension;
 use polars::prelude::{
     col, concat_str, cov, pearson_corr, spearman_rank_corr, when, IntoLazy, LiteralValue,
     SortOptions,
 };
-use polars::prelude::{DataType, EWMOptions, Expr, Literal, StrptimeOptions, TimeUnit};
+use polars::prelude::{
+    Column, DataType, Expr, GetOutput, Literal, NamedFrom, Series, StrptimeOptions, TimeUnit,
+};
+use rustler::Binary;
 
 // Useful to get an ExExpr vec into a vec of expressions.
 pub fn ex_expr_to_exprs(ex_exprs: Vec<ExExpr>) -> Vec<Expr> {
@@ -25,70 +32,70 @@ pub fn ex_expr_to_exprs(ex_exprs: Vec<ExExpr>) -> Vec<Expr> {
         .collect()
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_nil() -> ExExpr {
     let expr = Expr::Literal(LiteralValue::Null);
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_integer(number: i64) -> ExExpr {
     let expr = Expr::Literal(LiteralValue::Int64(number));
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_float(number: f64) -> ExExpr {
     let expr = Expr::Literal(LiteralValue::Float64(number));
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_string(string: String) -> ExExpr {
     let expr = string.lit();
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_boolean(boolean: bool) -> ExExpr {
     let expr = boolean.lit();
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_atom(atom: &str) -> ExExpr {
     let expr = cast_str_to_f64(atom).lit();
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_date(date: ExDate) -> ExExpr {
     ExExpr::new(date.lit())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_datetime(datetime: ExDateTime) -> ExExpr {
     ExExpr::new(datetime.lit())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_naive_datetime(naive_datetime: ExNaiveDateTime) -> ExExpr {
     ExExpr::new(naive_datetime.lit())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_duration(duration: ExDuration) -> ExExpr {
     ExExpr::new(duration.lit())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_series(series: ExSeries) -> ExExpr {
     let series = series.clone_inner();
     let expr = series.lit();
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_cast(data: ExExpr, to_dtype: ExSeriesDtype) -> ExExpr {
     let expr = data.clone_inner();
     let to_dtype = DataType::try_from(&to_dtype).expect("dtype is not valid");
@@ -96,13 +103,13 @@ pub fn expr_cast(data: ExExpr, to_dtype: ExSeriesDtype) -> ExExpr {
     ExExpr::new(expr.cast(to_dtype))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_column(name: &str) -> ExExpr {
     let expr = col(name);
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_equal(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -110,7 +117,7 @@ pub fn expr_equal(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr.eq(right_expr))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_not_equal(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -118,7 +125,7 @@ pub fn expr_not_equal(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr.neq(right_expr))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_greater(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -126,7 +133,7 @@ pub fn expr_greater(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr.gt(right_expr))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_greater_equal(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -134,7 +141,7 @@ pub fn expr_greater_equal(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr.gt_eq(right_expr))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_less(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -142,7 +149,7 @@ pub fn expr_less(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr.lt(right_expr))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_less_equal(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -150,7 +157,7 @@ pub fn expr_less_equal(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr.lt_eq(right_expr))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_binary_and(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -158,7 +165,7 @@ pub fn expr_binary_and(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr.and(right_expr))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_binary_or(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -166,7 +173,7 @@ pub fn expr_binary_or(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr.or(right_expr))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_binary_in(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -174,42 +181,42 @@ pub fn expr_binary_in(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr.is_in(right_expr))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_is_nil(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.is_null())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_is_not_nil(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.is_not_null())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_is_finite(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.is_finite())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_is_infinite(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.is_infinite())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_is_nan(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.is_nan())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_all_equal(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -218,42 +225,42 @@ pub fn expr_all_equal(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr.eq(right_expr).all(drop_nulls))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_slice(expr: ExExpr, offset: i64, length: u32) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.slice(offset, length))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_slice_by_indices(expr: ExExpr, indices_expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.gather(indices_expr.clone_inner()))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_head(expr: ExExpr, length: usize) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.head(Some(length)))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_tail(expr: ExExpr, length: usize) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.tail(Some(length)))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_shift(expr: ExExpr, offset: i64, _default: Option<ExExpr>) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.shift(offset.into()))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_sample_n(
     expr: ExExpr,
     n: u64,
@@ -266,7 +273,7 @@ pub fn expr_sample_n(
     ExExpr::new(expr.sample_n(n.lit(), with_replacement, shuffle, seed))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_sample_frac(
     expr: ExExpr,
     frac: f64,
@@ -279,7 +286,7 @@ pub fn expr_sample_frac(
     ExExpr::new(expr.sample_frac(frac.lit(), with_replacement, shuffle, seed))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_rank(
     expr: ExExpr,
     method: ExRankMethod,
@@ -292,7 +299,7 @@ pub fn expr_rank(
     ExExpr::new(expr.rank(rank_options, seed))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_peaks(data: ExExpr, min_or_max: &str) -> ExExpr {
     let expr = data.clone_inner();
     let type_expr = if min_or_max == "min" {
@@ -304,7 +311,7 @@ pub fn expr_peaks(data: ExExpr, min_or_max: &str) -> ExExpr {
     ExExpr::new(data.clone_inner().eq(type_expr))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_fill_missing_with_strategy(data: ExExpr, strategy: &str) -> ExExpr {
     let expr = data.clone_inner();
     let result_expr = match strategy {
@@ -318,14 +325,14 @@ pub fn expr_fill_missing_with_strategy(data: ExExpr, strategy: &str) -> ExExpr {
     ExExpr::new(result_expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_fill_missing_with_value(data: ExExpr, value: ExExpr) -> ExExpr {
     let expr = data.clone_inner();
     let value = value.clone_inner();
     ExExpr::new(expr.fill_null(value))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_add(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -333,7 +340,7 @@ pub fn expr_add(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr + right_expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_subtract(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -341,7 +348,7 @@ pub fn expr_subtract(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr - right_expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_divide(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner().cast(DataType::Float64);
     let right_expr = right.clone_inner().cast(DataType::Float64);
@@ -349,7 +356,7 @@ pub fn expr_divide(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr / right_expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_quotient(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -362,7 +369,7 @@ pub fn expr_quotient(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(quotient)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_remainder(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -378,7 +385,7 @@ pub fn expr_remainder(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(result)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_multiply(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -386,7 +393,7 @@ pub fn expr_multiply(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr * right_expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_pow(left: ExExpr, right: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
     let right_expr = right.clone_inner();
@@ -394,125 +401,145 @@ pub fn expr_pow(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(left_expr.pow(right_expr))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_log(left: ExExpr, base: f64) -> ExExpr {
     let left_expr = left.clone_inner();
 
     ExExpr::new(left_expr.log(base))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_log_natural(left: ExExpr) -> ExExpr {
     let left_expr = left.clone_inner();
 
     ExExpr::new(left_expr.log(std::f64::consts::E))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_exp(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.exp())
 }
 
-#[rustler::nif]
+#[nif]
+pub fn expr_log1p(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.log1p())
+}
+
+#[nif]
+pub fn expr_expm1(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.map(
+        |column| {
+            let series = expm1(column.as_materialized_series())?;
+            Ok(Some(Column::from(series)))
+        },
+        GetOutput::from_type(DataType::Float64),
+    ))
+}
+
+#[nif]
 pub fn expr_sum(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.sum())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_min(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.min())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_max(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.max())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_argmax(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.arg_max())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_argmin(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.arg_min())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_mean(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.mean())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_median(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.median())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_mode(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.mode())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_product(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.product())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_abs(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.abs())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_variance(expr: ExExpr, ddof: u8) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.var(ddof))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_standard_deviation(expr: ExExpr, ddof: u8) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.std(ddof))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_quantile(expr: ExExpr, quantile: f64) -> ExExpr {
     let expr = expr.clone_inner();
     let strategy = crate::parse_quantile_interpol_options("nearest");
     ExExpr::new(expr.quantile(quantile.into(), strategy))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_skew(data: ExExpr, bias: bool) -> ExExpr {
     let expr = data.clone_inner();
     ExExpr::new(expr.skew(bias))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_correlation(left: ExExpr, right: ExExpr, method: ExCorrelationMethod) -> ExExpr {
     let left_expr = left.clone_inner().cast(DataType::Float64);
     let right_expr = right.clone_inner().cast(DataType::Float64);
@@ -525,82 +552,89 @@ pub fn expr_correlation(left: ExExpr, right: ExExpr, method: ExCorrelationMethod
     }
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_covariance(left: ExExpr, right: ExExpr, ddof: u8) -> ExExpr {
     let left_expr = left.clone_inner().cast(DataType::Float64);
     let right_expr = right.clone_inner().cast(DataType::Float64);
     ExExpr::new(cov(left_expr, right_expr, ddof))
 }
 
-#[rustler::nif]
+#[nif]
+pub fn expr_index_of(expr: ExExpr, value: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(index_of_expr(expr, value.clone_inner()))
+}
+
+#[nif]
 pub fn expr_all(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.all(true))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_any(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.any(true))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_alias(expr: ExExpr, name: &str) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.alias(name))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_count(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.count())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_nil_count(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.null_count())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_size(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.len())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_n_distinct(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.n_unique())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_first(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.first())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_last(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.last())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_format(exprs: Vec<ExExpr>) -> ExExpr {
     ExExpr::new(concat_str(ex_expr_to_exprs(exprs), "", true))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_concat(exprs: Vec<ExExpr>) -> ExExpr {
     let mut iter = exprs.iter();
     let mut result = iter.next().unwrap().clone_inner();
@@ -612,7 +646,7 @@ pub fn expr_concat(exprs: Vec<ExExpr>) -> ExExpr {
     ExExpr::new(result)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_coalesce(left: ExExpr, right: ExExpr) -> ExExpr {
     let predicate = left.clone_inner().is_not_null();
     let left_expr = left.clone_inner();
@@ -623,7 +657,7 @@ pub fn expr_coalesce(left: ExExpr, right: ExExpr) -> ExExpr {
     ExExpr::new(condition)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_select(predicate: ExExpr, on_true: ExExpr, on_false: ExExpr) -> ExExpr {
     let predicate_expr = predicate.clone_inner();
     let on_true_expr = on_true.clone_inner();
@@ -639,7 +673,7 @@ pub fn expr_select(predicate: ExExpr, on_true: ExExpr, on_false: ExExpr) -> ExEx
 // window functions
 macro_rules! init_window_expr_fun {
     ($name:ident, $fun:ident) => {
-        #[rustler::nif(schedule = "DirtyCpu")]
+        #[nif(schedule = "DirtyCpu")]
         pub fn $name(
             data: ExExpr,
             window_size: usize,
@@ -647,12 +681,15 @@ macro_rules! init_window_expr_fun {
             min_periods: Option<usize>,
             center: bool,
         ) -> ExExpr {
-            let expr = match weights.as_deref() {
-                Some([]) | None => data.clone_inner(),
-                _ => data.clone_inner().cast(DataType::Float64),
-            };
-            let opts = rolling_opts_fixed_window(window_size, weights, min_periods, center);
-            ExExpr::new(expr.$fun(opts))
+            let expr = data.clone_inner();
+            ExExpr::new(rolling_expr(
+                expr,
+                window_size,
+                weights,
+                min_periods,
+                center,
+                Expr::$fun,
+            ))
         }
     };
 }
@@ -663,7 +700,7 @@ init_window_expr_fun!(expr_window_sum, rolling_sum);
 init_window_expr_fun!(expr_window_mean, rolling_mean);
 init_window_expr_fun!(expr_window_median, rolling_median);
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn expr_window_standard_deviation(
     data: ExExpr,
     window_size: usize,
@@ -672,35 +709,42 @@ pub fn expr_window_standard_deviation(
     center: bool,
 ) -> ExExpr {
     let expr = data.clone_inner();
-    let opts = rolling_opts_fixed_window(window_size, weights, min_periods, center);
-    ExExpr::new(expr.rolling_std(opts).cast(DataType::Float64))
+    let expr = rolling_expr(
+        expr,
+        window_size,
+        weights,
+        min_periods,
+        center,
+        Expr::rolling_std,
+    );
+    ExExpr::new(expr.cast(DataType::Float64))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_cumulative_min(data: ExExpr, reverse: bool) -> ExExpr {
     let expr = data.clone_inner();
     ExExpr::new(expr.cum_min(reverse))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_cumulative_max(data: ExExpr, reverse: bool) -> ExExpr {
     let expr = data.clone_inner();
     ExExpr::new(expr.cum_max(reverse))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_cumulative_sum(data: ExExpr, reverse: bool) -> ExExpr {
     let expr = data.clone_inner();
     ExExpr::new(expr.cum_sum(reverse))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_cumulative_product(data: ExExpr, reverse: bool) -> ExExpr {
     let expr = data.clone_inner();
     ExExpr::new(expr.cum_prod(reverse))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_ewm_mean(
     data: ExExpr,
     alpha: f64,
@@ -709,11 +753,11 @@ pub fn expr_ewm_mean(
     ignore_nulls: bool,
 ) -> ExExpr {
     let expr = data.clone_inner();
-    let opts = ewm_opts(alpha, adjust, min_periods, ignore_nulls);
+    let opts = ewm_opts(alpha, adjust, false, min_periods, ignore_nulls);
     ExExpr::new(expr.ewm_mean(opts))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_ewm_standard_deviation(
     data: ExExpr,
     alpha: f64,
@@ -723,17 +767,11 @@ pub fn expr_ewm_standard_deviation(
     ignore_nulls: bool,
 ) -> ExExpr {
     let expr = data.clone_inner();
-    let opts = EWMOptions {
-        alpha,
-        adjust,
-        bias,
-        min_periods,
-        ignore_nulls,
-    };
+    let opts = ewm_opts(alpha, adjust, bias, min_periods, ignore_nulls);
     ExExpr::new(expr.ewm_std(opts))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_ewm_variance(
     data: ExExpr,
     alpha: f64,
@@ -743,24 +781,18 @@ pub fn expr_ewm_variance(
     ignore_nulls: bool,
 ) -> ExExpr {
     let expr = data.clone_inner();
-    let opts = EWMOptions {
-        alpha,
-        adjust,
-        bias,
-        min_periods,
-        ignore_nulls,
-    };
+    let opts = ewm_opts(alpha, adjust, bias, min_periods, ignore_nulls);
     ExExpr::new(expr.ewm_var(opts))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_reverse(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.reverse())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_sort(
     expr: ExExpr,
     descending: bool,
@@ -781,7 +813,7 @@ pub fn expr_sort(
     ExExpr::new(expr.sort(opts))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_argsort(
     expr: ExExpr,
     descending: bool,
@@ -802,27 +834,27 @@ pub fn expr_argsort(
     ExExpr::new(expr.arg_sort(opts))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_distinct(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.unique_stable())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_unordered_distinct(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.unique())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_unary_not(expr: ExExpr) -> ExExpr {
     let predicate = expr.clone_inner();
     ExExpr::new(predicate.not())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_describe_filter_plan(data: ExDataFrame, expr: ExExpr) -> String {
     let df = data.clone();
     let expressions = expr.clone_inner();
@@ -832,31 +864,78 @@ pub fn expr_describe_filter_plan(data: ExDataFrame, expr: ExExpr) -> String {
         .expect("error")
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_contains(expr: ExExpr, pattern: &str) -> ExExpr {
     let expr = expr.clone_inner();
     ExExpr::new(expr.str().contains_literal(pattern.lit()))
 }
 
-#[rustler::nif]
+#[nif]
+pub fn expr_contains_any(
+    expr: ExExpr,
+    patterns: Vec<String>,
+    ascii_case_insensitive: bool,
+) -> ExExpr {
+    let expr = expr.clone_inner();
+    let patterns = Series::new("patterns".into(), patterns).lit();
+    ExExpr::new(expr.str().contains_any(patterns, ascii_case_insensitive))
+}
+
+#[nif]
+pub fn expr_levenshtein(left: ExExpr, right: ExExpr) -> ExExpr {
+    let left = left.clone_inner();
+    let right = right.clone_inner();
+
+    ExExpr::new(dsl::map_multiple(
+        |columns| {
+            let distance = levenshtein_distance(
+                columns[0].as_materialized_series(),
+                columns[1].as_materialized_series(),
+            )?;
+            Ok(Some(Column::from(distance)))
+        },
+        [left, right],
+        GetOutput::from_type(DataType::UInt32),
+    ))
+}
+
+#[nif]
+pub fn expr_jaro_winkler(left: ExExpr, right: ExExpr) -> ExExpr {
+    let left = left.clone_inner();
+    let right = right.clone_inner();
+
+    ExExpr::new(dsl::map_multiple(
+        |columns| {
+            let similarity = jaro_winkler_similarity(
+                columns[0].as_materialized_series(),
+                columns[1].as_materialized_series(),
+            )?;
+            Ok(Some(Column::from(similarity)))
+        },
+        [left, right],
+        GetOutput::from_type(DataType::Float64),
+    ))
+}
+
+#[nif]
 pub fn expr_re_contains(expr: ExExpr, pattern: &str) -> ExExpr {
     let expr = expr.clone_inner();
     ExExpr::new(expr.str().contains(pattern.lit(), true))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_upcase(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
     ExExpr::new(expr.str().to_uppercase())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_downcase(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
     ExExpr::new(expr.str().to_lowercase())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_strip(expr: ExExpr, string: Option<String>) -> ExExpr {
     let expr = expr.clone_inner();
     let matches_expr = match string {
@@ -866,7 +945,7 @@ pub fn expr_strip(expr: ExExpr, string: Option<String>) -> ExExpr {
     ExExpr::new(expr.str().strip_chars(matches_expr))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_lstrip(expr: ExExpr, string: Option<String>) -> ExExpr {
     let expr = expr.clone_inner();
     let matches_expr = match string {
@@ -876,7 +955,7 @@ pub fn expr_lstrip(expr: ExExpr, string: Option<String>) -> ExExpr {
     ExExpr::new(expr.str().strip_chars_start(matches_expr))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_rstrip(expr: ExExpr, string: Option<String>) -> ExExpr {
     let expr = expr.clone_inner();
     let matches_expr = match string {
@@ -886,7 +965,93 @@ pub fn expr_rstrip(expr: ExExpr, string: Option<String>) -> ExExpr {
     ExExpr::new(expr.str().strip_chars_end(matches_expr))
 }
 
-#[rustler::nif]
+#[nif]
+pub fn expr_strip_prefix(expr: ExExpr, prefix: String) -> ExExpr {
+    let expr = expr.clone_inner();
+    ExExpr::new(expr.str().strip_prefix(prefix.lit()))
+}
+
+#[nif]
+pub fn expr_strip_suffix(expr: ExExpr, suffix: String) -> ExExpr {
+    let expr = expr.clone_inner();
+    ExExpr::new(expr.str().strip_suffix(suffix.lit()))
+}
+
+#[nif]
+pub fn expr_str_to_integer(expr: ExExpr, base: u32, strict: bool) -> ExExpr {
+    let expr = expr.clone_inner();
+    ExExpr::new(expr.str().to_integer(base.lit(), strict))
+}
+
+#[nif]
+pub fn expr_str_to_float(expr: ExExpr, strict: bool) -> ExExpr {
+    let expr = expr.clone_inner();
+    let expr = if strict {
+        expr.strict_cast(DataType::Float64)
+    } else {
+        expr.cast(DataType::Float64)
+    };
+
+    ExExpr::new(expr)
+}
+
+#[nif]
+pub fn expr_base64_encode(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+    ExExpr::new(expr.cast(DataType::Binary).binary().base64_encode())
+}
+
+#[nif]
+pub fn expr_base64_decode(expr: ExExpr, strict: bool) -> ExExpr {
+    let expr = expr.clone_inner();
+    ExExpr::new(expr.cast(DataType::Binary).binary().base64_decode(strict))
+}
+
+#[nif]
+pub fn expr_hex_encode(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+    ExExpr::new(expr.cast(DataType::Binary).binary().hex_encode())
+}
+
+#[nif]
+pub fn expr_hex_decode(expr: ExExpr, strict: bool) -> ExExpr {
+    let expr = expr.clone_inner();
+    ExExpr::new(expr.cast(DataType::Binary).binary().hex_decode(strict))
+}
+
+#[nif]
+pub fn expr_binary_size(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+    ExExpr::new(expr.binary().size_bytes())
+}
+
+#[nif]
+pub fn expr_binary_contains(expr: ExExpr, pattern: Binary) -> ExExpr {
+    let expr = expr.clone_inner();
+    ExExpr::new(expr.binary().contains_literal(pattern.as_slice().lit()))
+}
+
+#[nif]
+pub fn expr_binary_starts_with(expr: ExExpr, prefix: Binary) -> ExExpr {
+    let expr = expr.clone_inner();
+    ExExpr::new(expr.binary().starts_with(prefix.as_slice().lit()))
+}
+
+#[nif]
+pub fn expr_binary_ends_with(expr: ExExpr, suffix: Binary) -> ExExpr {
+    let expr = expr.clone_inner();
+    ExExpr::new(expr.binary().ends_with(suffix.as_slice().lit()))
+}
+
+// Aggregates the string values (of each group, when used in a group by)
+// into a single string, using the given delimiter.
+#[nif]
+pub fn expr_str_join(expr: ExExpr, delimiter: &str, ignore_nils: bool) -> ExExpr {
+    let expr = expr.clone_inner();
+    ExExpr::new(expr.str().join(delimiter, ignore_nils))
+}
+
+#[nif]
 pub fn expr_substring(expr: ExExpr, offset: i64, length: Option<u64>) -> ExExpr {
     let length = match length {
         Some(l) => l.lit(),
@@ -896,99 +1061,147 @@ pub fn expr_substring(expr: ExExpr, offset: i64, length: Option<u64>) -> ExExpr
     ExExpr::new(expr.str().slice(offset.lit(), length))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_split(expr: ExExpr, substring: String) -> ExExpr {
     let expr = expr.clone_inner();
     ExExpr::new(expr.str().split(substring.lit()))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_replace(expr: ExExpr, pat: String, value: String) -> ExExpr {
     let expr = expr.clone_inner();
     ExExpr::new(expr.str().replace_all(pat.lit(), value.lit(), true))
 }
 
-#[rustler::nif]
+#[nif]
+pub fn expr_replace_many(
+    expr: ExExpr,
+    patterns: Vec<String>,
+    replacements: Vec<String>,
+    ascii_case_insensitive: bool,
+) -> ExExpr {
+    let expr = expr.clone_inner();
+    let patterns = Series::new("patterns".into(), patterns).lit();
+    let replacements = Series::new("replacements".into(), replacements).lit();
+    ExExpr::new(
+        expr.str()
+            .replace_many(patterns, replacements, ascii_case_insensitive),
+    )
+}
+
+// Values listed in `old` are replaced by the value at the same position in
+// `new`. Anything else is kept as is.
+#[nif]
+pub fn expr_remap(expr: ExExpr, old: ExExpr, new: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.replace(old.clone_inner(), new.clone_inner()))
+}
+
+// Like `expr_remap`, but unmatched values become `default`. Without a
+// default, unmatched values raise when the expression is evaluated.
+#[nif]
+pub fn expr_remap_strict(
+    expr: ExExpr,
+    old: ExExpr,
+    new: ExExpr,
+    default: Option<ExExpr>,
+    return_dtype: Option<ExSeriesDtype>,
+) -> Result<ExExpr, ExplorerError> {
+    let expr = expr.clone_inner();
+    let return_dtype = return_dtype
+        .map(|dtype| DataType::try_from(&dtype))
+        .transpose()?;
+
+    Ok(ExExpr::new(expr.replace_strict(
+        old.clone_inner(),
+        new.clone_inner(),
+        default.map(|default| default.clone_inner()),
+        return_dtype,
+    )))
+}
+
+#[nif]
 pub fn expr_re_replace(expr: ExExpr, pat: String, value: String) -> ExExpr {
     let expr = expr.clone_inner();
     ExExpr::new(expr.str().replace_all(pat.lit(), value.lit(), false))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_round(expr: ExExpr, decimals: u32) -> ExExpr {
     let expr = expr.clone_inner();
     ExExpr::new(expr.round(decimals))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_floor(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
     ExExpr::new(expr.floor())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_ceil(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
     ExExpr::new(expr.ceil())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_sin(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.sin())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_cos(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.cos())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_tan(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.tan())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_asin(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.arcsin())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_acos(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.arccos())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_atan(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.arctan())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_degrees(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.degrees())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_radians(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.radians())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_strptime(expr: ExExpr, format_string: &str) -> ExExpr {
     let options = StrptimeOptions {
         format: Some(format_string.into()),
@@ -1004,19 +1217,40 @@ pub fn expr_strptime(expr: ExExpr, format_string: &str) -> ExExpr {
     ))
 }
 
-#[rustler::nif]
+#[nif]
+pub fn expr_strptime_many(
+    expr: ExExpr,
+    formats: Vec<String>,
+    precision: Option<ExTimeUnit>,
+    time_zone: Option<&str>,
+    ambiguous: &str,
+    non_existent: ExNonExistent,
+) -> Result<ExExpr, ExplorerError> {
+    let expr = strptime_many_expr(
+        expr.clone_inner(),
+        formats,
+        precision,
+        time_zone,
+        ambiguous,
+        non_existent,
+    )?;
+
+    Ok(ExExpr::new(expr))
+}
+
+#[nif]
 pub fn expr_strftime(expr: ExExpr, format_string: &str) -> ExExpr {
     ExExpr::new(expr.clone_inner().dt().strftime(format_string))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_clip_integer(expr: ExExpr, min: i64, max: i64) -> ExExpr {
     let expr = expr.clone_inner().clip(min.lit(), max.lit());
 
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_clip_float(expr: ExExpr, min: f64, max: f64) -> ExExpr {
     let expr = expr
         .clone_inner()
@@ -1026,70 +1260,267 @@ pub fn expr_clip_float(expr: ExExpr, min: f64, max: f64) -> ExExpr {
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_day_of_week(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.dt().weekday())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_day_of_year(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.dt().ordinal_day())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_week_of_year(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.dt().week())
 }
 
-#[rustler::nif]
+#[nif]
+pub fn expr_iso_year(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().iso_year())
+}
+
+#[nif]
 pub fn expr_month(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.dt().month())
 }
 
-#[rustler::nif]
+#[nif]
+pub fn expr_quarter(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().quarter())
+}
+
+#[nif]
 pub fn expr_year(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.dt().year())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_hour(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.dt().hour())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_minute(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.dt().minute())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_second(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.dt().second())
 }
 
-#[rustler::nif]
+#[nif]
+pub fn expr_millisecond(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().millisecond())
+}
+
+#[nif]
+pub fn expr_microsecond(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().microsecond())
+}
+
+#[nif]
+pub fn expr_nanosecond(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().nanosecond())
+}
+
+#[nif]
+pub fn expr_dt_truncate(expr: ExExpr, every: String) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().truncate(every.lit()))
+}
+
+#[nif]
+pub fn expr_dt_round(expr: ExExpr, every: String) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().round(every.lit()))
+}
+
+#[nif]
+pub fn expr_dt_offset_by(expr: ExExpr, interval: String) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().offset_by(interval.lit()))
+}
+
+#[nif]
+pub fn expr_month_start(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().month_start())
+}
+
+#[nif]
+pub fn expr_month_end(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().month_end())
+}
+
+#[nif]
+pub fn expr_days_in_month(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().month_end().dt().day())
+}
+
+#[nif]
+pub fn expr_is_leap_year(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().is_leap_year())
+}
+
+#[nif]
+pub fn expr_convert_time_zone(expr: ExExpr, time_zone: String) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().convert_time_zone(time_zone.into()))
+}
+
+#[nif]
+pub fn expr_replace_time_zone(
+    expr: ExExpr,
+    time_zone: Option<String>,
+    ambiguous: String,
+    non_existent: ExNonExistent,
+) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().replace_time_zone(
+        time_zone.map(|value| value.into()),
+        ambiguous.lit(),
+        non_existent.into(),
+    ))
+}
+
+#[nif]
+pub fn expr_base_utc_offset(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().base_utc_offset())
+}
+
+#[nif]
+pub fn expr_dst_offset(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().dst_offset())
+}
+
+#[nif]
+pub fn expr_dt_to_date(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().date())
+}
+
+#[nif]
+pub fn expr_dt_to_time(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().time())
+}
+
+#[nif]
+pub fn expr_total_days(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().total_days())
+}
+
+#[nif]
+pub fn expr_total_hours(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().total_hours())
+}
+
+#[nif]
+pub fn expr_total_minutes(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().total_minutes())
+}
+
+#[nif]
+pub fn expr_total_seconds(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().total_seconds())
+}
+
+#[nif]
+pub fn expr_total_milliseconds(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().total_milliseconds())
+}
+
+#[nif]
+pub fn expr_total_microseconds(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().total_microseconds())
+}
+
+#[nif]
+pub fn expr_total_nanoseconds(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.dt().total_nanoseconds())
+}
+
+#[nif]
+pub fn expr_timestamp(expr: ExExpr, unit: ExTimestampUnit) -> ExExpr {
+    let expr = expr.clone_inner().dt();
+    let timestamp = match unit {
+        ExTimestampUnit::Second => expr.timestamp(TimeUnit::Milliseconds).floor_div(1000.lit()),
+        ExTimestampUnit::Millisecond => expr.timestamp(TimeUnit::Milliseconds),
+        ExTimestampUnit::Microsecond => expr.timestamp(TimeUnit::Microseconds),
+        ExTimestampUnit::Nanosecond => expr.timestamp(TimeUnit::Nanoseconds),
+    };
+
+    ExExpr::new(timestamp)
+}
+
+#[nif]
 pub fn expr_join(expr: ExExpr, sep: String) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.list().join(sep.lit(), true))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_int_range(start: ExExpr, end: ExExpr, step: i64, dtype: ExSeriesDtype) -> ExExpr {
     let start = start.clone_inner();
     let end = end.clone_inner();
@@ -1099,14 +1530,50 @@ pub fn expr_int_range(start: ExExpr, end: ExExpr, step: i64, dtype: ExSeriesDtyp
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[allow(clippy::too_many_arguments)]
+#[nif]
+pub fn expr_duration_from_parts(
+    weeks: ExExpr,
+    days: ExExpr,
+    hours: ExExpr,
+    minutes: ExExpr,
+    seconds: ExExpr,
+    milliseconds: ExExpr,
+    microseconds: ExExpr,
+    nanoseconds: ExExpr,
+    precision: ExTimeUnit,
+) -> Result<ExExpr, ExplorerError> {
+    let args = dsl::DurationArgs {
+        weeks: weeks.clone_inner(),
+        days: days.clone_inner(),
+        hours: hours.clone_inner(),
+        minutes: minutes.clone_inner(),
+        seconds: seconds.clone_inner(),
+        milliseconds: milliseconds.clone_inner(),
+        microseconds: microseconds.clone_inner(),
+        nanoseconds: nanoseconds.clone_inner(),
+        time_unit: TimeUnit::try_from(&precision)?,
+    };
+
+    Ok(ExExpr::new(dsl::duration(args)))
+}
+
+// Gives one row per list element. Empty and nil lists give a nil row.
+#[nif]
+pub fn expr_explode(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.explode())
+}
+
+#[nif]
 pub fn expr_lengths(expr: ExExpr) -> ExExpr {
     let expr = expr.clone_inner();
 
     ExExpr::new(expr.list().len())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_member(expr: ExExpr, value: ExValidValue, inner_dtype: ExSeriesDtype) -> ExExpr {
     let expr = expr.clone_inner();
     let inner_dtype = DataType::try_from(&inner_dtype).unwrap();
@@ -1117,26 +1584,190 @@ pub fn expr_member(expr: ExExpr, value: ExValidValue, inner_dtype: ExSeriesDtype
     )
 }
 
-#[rustler::nif]
+// Negative indices count from the end of each list. Out of bounds
+// indices give nil.
+#[nif]
+pub fn expr_list_get(expr: ExExpr, index: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.list().get(index.clone_inner(), true))
+}
+
+// A negative offset counts from the end of each list. A nil length
+// takes everything after the offset.
+#[nif]
+pub fn expr_list_slice(expr: ExExpr, offset: ExExpr, length: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(
+        expr.list()
+            .slice(offset.clone_inner(), length.clone_inner()),
+    )
+}
+
+#[nif]
+pub fn expr_list_head(expr: ExExpr, n: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.list().head(n.clone_inner()))
+}
+
+#[nif]
+pub fn expr_list_tail(expr: ExExpr, n: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.list().tail(n.clone_inner()))
+}
+
+// Keeps the first occurrence of each value, in order.
+#[nif]
+pub fn expr_list_unique(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.list().unique_stable())
+}
+
+#[nif]
+pub fn expr_list_sort(expr: ExExpr, descending: bool, nulls_last: bool) -> ExExpr {
+    let expr = expr.clone_inner();
+    let opts = SortOptions {
+        descending,
+        nulls_last,
+        ..Default::default()
+    };
+
+    ExExpr::new(expr.list().sort(opts))
+}
+
+#[nif]
+pub fn expr_list_reverse(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.list().reverse())
+}
+
+// Polars refers to the values an expression is evaluated on, such as the
+// elements of a list in `list().eval` or the values of a pivot, with the
+// unnamed column. It has no Rust helper for it, unlike `pl.element()`.
+pub fn element() -> Expr {
+    col("")
+}
+
+// Stands for the elements of each list inside `expr_list_eval`.
+#[nif]
+pub fn expr_element() -> ExExpr {
+    ExExpr::new(element())
+}
+
+// Runs `inner` on the elements of each list, which are referred to with
+// `expr_element`. Other columns cannot be used inside `inner`.
+#[nif]
+pub fn expr_list_eval(expr: ExExpr, inner: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.list().eval(inner.clone_inner(), false))
+}
+
+// The set operations compare the lists row by row. `other` may also be a
+// single list, which is then compared with every row.
+#[nif]
+pub fn expr_list_set_union(expr: ExExpr, other: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.list().union(other.clone_inner()))
+}
+
+#[nif]
+pub fn expr_list_set_intersection(expr: ExExpr, other: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.list().set_intersection(other.clone_inner()))
+}
+
+#[nif]
+pub fn expr_list_set_difference(expr: ExExpr, other: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.list().set_difference(other.clone_inner()))
+}
+
+#[nif]
+pub fn expr_list_sum(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.list().sum())
+}
+
+#[nif]
+pub fn expr_list_mean(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.list().mean())
+}
+
+#[nif]
+pub fn expr_list_min(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.list().min())
+}
+
+#[nif]
+pub fn expr_list_max(expr: ExExpr) -> ExExpr {
+    let expr = expr.clone_inner();
+
+    ExExpr::new(expr.list().max())
+}
+
+#[nif]
 pub fn expr_field(expr: ExExpr, name: &str) -> ExExpr {
     let expr = expr.clone_inner().struct_().field_by_name(name);
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_json_decode(expr: ExExpr, ex_dtype: ExSeriesDtype) -> ExExpr {
     let dtype = DataType::try_from(&ex_dtype).unwrap();
     let expr = expr.clone_inner().str().json_decode(Some(dtype), None);
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+// Polars can only encode structs, so we wrap the value in a single-field
+// struct and remove the wrapping afterwards. This way lists are supported too.
+#[cfg(feature = "ndjson")]
+#[nif]
+pub fn expr_json_encode(expr: ExExpr) -> Result<ExExpr, ExplorerError> {
+    let expr = expr.clone_inner();
+    let encoded = dsl::as_struct(vec![expr.clone().alias("v")])
+        .struct_()
+        .json_encode()
+        .str()
+        .strip_prefix(r#"{"v":"#.lit())
+        .str()
+        .strip_suffix("}".lit());
+
+    Ok(ExExpr::new(
+        when(expr.is_null())
+            .then(Expr::Literal(LiteralValue::Null).cast(DataType::String))
+            .otherwise(encoded),
+    ))
+}
+
+#[cfg(not(feature = "ndjson"))]
+#[nif]
+pub fn expr_json_encode(_expr: ExExpr) -> Result<ExExpr, ExplorerError> {
+    Err(ExplorerError::Other("Explorer was compiled without the \"ndjson\" feature enabled. \
+        This is mostly due to this feature being incompatible with your computer's architecture. \
+        Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
+}
+
+#[nif]
 pub fn expr_json_path_match(expr: ExExpr, json_path: String) -> ExExpr {
     let expr = expr.clone_inner().str().json_path_match(json_path.lit());
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_split_into(expr: ExExpr, by: String, names: Vec<String>) -> ExExpr {
     let expr = expr
         .clone_inner()
@@ -1148,7 +1779,7 @@ pub fn expr_split_into(expr: ExExpr, by: String, names: Vec<String>) -> ExExpr {
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_struct(ex_exprs: Vec<ExExpr>) -> ExExpr {
     let exprs = ex_exprs.iter().map(|e| e.clone_inner()).collect();
     let expr = dsl::as_struct(exprs);
@@ -1156,31 +1787,31 @@ pub fn expr_struct(ex_exprs: Vec<ExExpr>) -> ExExpr {
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_over(left: ExExpr, groups: Vec<ExExpr>) -> ExExpr {
     let expr = left.clone_inner().over(groups);
     ExExpr::new(expr)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_count_matches(expr: ExExpr, pattern: &str) -> ExExpr {
     let expr = expr.clone_inner();
     ExExpr::new(expr.str().count_matches(pattern.lit(), true))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_re_count_matches(expr: ExExpr, pattern: &str) -> ExExpr {
     let expr = expr.clone_inner();
     ExExpr::new(expr.str().count_matches(pattern.lit(), false))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_re_scan(expr: ExExpr, pattern: &str) -> ExExpr {
     let expr = expr.clone_inner();
     ExExpr::new(expr.str().extract_all(pattern.lit()))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn expr_re_named_captures(expr: ExExpr, pattern: &str) -> ExExpr {
     let expr = expr.clone_inner();
     ExExpr::new(
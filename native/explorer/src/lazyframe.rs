@@ -1,25 +1,40 @@
 use crate::{
-    datatypes::ExSeriesDtype, expressions::ex_expr_to_exprs, ExDataFrame, ExExpr, ExLazyFrame,
-    ExplorerError,
+    cancellation::{self, ExCancelTokenRef},
+    datatypes::ExSeriesDtype,
+    expressions::ex_expr_to_exprs,
+    local_message::with_progress,
+    ExDataFrame, ExExpr, ExLazyFrame, ExplorerError,
 };
+use explorer_macros::nif;
 use polars::{lazy::dsl::Selector, prelude::*};
+use rustler::{LocalPid, ResourceArc, Term};
 
 // Loads the IO functions for read/writing CSV, NDJSON, Parquet, etc.
 pub mod io;
 
-#[rustler::nif(schedule = "DirtyCpu")]
-pub fn lf_compute(data: ExLazyFrame) -> Result<ExDataFrame, ExplorerError> {
-    let df = data.clone_inner().collect()?;
+#[nif(schedule = "DirtyCpu", measure)]
+pub fn lf_compute(
+    data: ExLazyFrame,
+    cancel_token: Option<ResourceArc<ExCancelTokenRef>>,
+    progress: Option<(LocalPid, Term)>,
+) -> Result<ExDataFrame, ExplorerError> {
+    // Polars doesn't tell how far a query is while it runs, so progress is
+    // only reported once it is done.
+    let df = with_progress(progress, |report| {
+        let df = cancellation::collect(data.clone_inner(), cancel_token)?;
+        report(df.height())?;
+        Ok(df)
+    })?;
 
     Ok(ExDataFrame::new(df))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu", measure)]
 pub fn lf_fetch(data: ExLazyFrame, n_rows: usize) -> Result<ExDataFrame, ExplorerError> {
     Ok(ExDataFrame::new(data.clone_inner().fetch(n_rows)?))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_describe_plan(data: ExLazyFrame, optimized: bool) -> Result<String, ExplorerError> {
     let lf = data.clone_inner();
     let plan = match optimized {
@@ -29,7 +44,7 @@ pub fn lf_describe_plan(data: ExLazyFrame, optimized: bool) -> Result<String, Ex
     Ok(plan)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_head(
     data: ExLazyFrame,
     length: u32,
@@ -45,7 +60,7 @@ pub fn lf_head(
     Ok(ExLazyFrame::new(result_lf))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_tail(
     data: ExLazyFrame,
     length: u32,
@@ -61,7 +76,7 @@ pub fn lf_tail(
     Ok(ExLazyFrame::new(result_lf))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_names(data: ExLazyFrame) -> Result<Vec<String>, ExplorerError> {
     let mut lf = data.clone_inner();
     let names = lf
@@ -73,7 +88,7 @@ pub fn lf_names(data: ExLazyFrame) -> Result<Vec<String>, ExplorerError> {
     Ok(names)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_dtypes(data: ExLazyFrame) -> Result<Vec<ExSeriesDtype>, ExplorerError> {
     let mut dtypes: Vec<ExSeriesDtype> = vec![];
     let schema = data.clone_inner().collect_schema()?;
@@ -85,19 +100,19 @@ pub fn lf_dtypes(data: ExLazyFrame) -> Result<Vec<ExSeriesDtype>, ExplorerError>
     Ok(dtypes)
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_select(data: ExLazyFrame, columns: Vec<&str>) -> Result<ExLazyFrame, ExplorerError> {
     let lf = data.clone_inner().select(&[cols(columns)]);
     Ok(ExLazyFrame::new(lf))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_drop(data: ExLazyFrame, columns: Vec<&str>) -> Result<ExLazyFrame, ExplorerError> {
     let lf = data.clone_inner().select(&[col("*").exclude(columns)]);
     Ok(ExLazyFrame::new(lf))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_slice(
     data: ExLazyFrame,
     offset: i64,
@@ -117,19 +132,55 @@ pub fn lf_slice(
     Ok(ExLazyFrame::new(result_lf))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_explode(data: ExLazyFrame, columns: Vec<&str>) -> Result<ExLazyFrame, ExplorerError> {
     let lf = data.clone_inner().explode(columns);
     Ok(ExLazyFrame::new(lf))
 }
 
-#[rustler::nif]
-pub fn lf_unnest(data: ExLazyFrame, columns: Vec<&str>) -> Result<ExLazyFrame, ExplorerError> {
-    let lf = data.clone_inner().unnest(columns);
+#[nif]
+pub fn lf_unnest(
+    data: ExLazyFrame,
+    columns: Vec<&str>,
+    separator: Option<&str>,
+) -> Result<ExLazyFrame, ExplorerError> {
+    let lf = unnest(data.clone_inner(), columns, separator)?;
     Ok(ExLazyFrame::new(lf))
 }
 
-#[rustler::nif]
+// When a separator is given, fields are renamed to "{column}{separator}{field}"
+// before unnesting, so structs sharing field names do not clash.
+pub fn unnest(
+    mut lf: LazyFrame,
+    columns: Vec<&str>,
+    separator: Option<&str>,
+) -> Result<LazyFrame, ExplorerError> {
+    if let Some(separator) = separator {
+        let schema = lf.collect_schema()?;
+        let renames = columns
+            .iter()
+            .map(|column| match schema.try_get(column)? {
+                DataType::Struct(fields) => {
+                    let names: Vec<String> = fields
+                        .iter()
+                        .map(|field| format!("{column}{separator}{}", field.name()))
+                        .collect();
+
+                    Ok(col(*column).struct_().rename_fields(names))
+                }
+                dtype => Err(ExplorerError::Other(format!(
+                    "expected column {column} to be a struct, got: {dtype}"
+                ))),
+            })
+            .collect::<Result<Vec<Expr>, ExplorerError>>()?;
+
+        lf = lf.with_columns(renames);
+    }
+
+    Ok(lf.unnest(columns))
+}
+
+#[nif]
 pub fn lf_filter_with(data: ExLazyFrame, ex_expr: ExExpr) -> Result<ExLazyFrame, ExplorerError> {
     let lf = data.clone_inner();
     let expr = ex_expr.clone_inner();
@@ -137,13 +188,14 @@ pub fn lf_filter_with(data: ExLazyFrame, ex_expr: ExExpr) -> Result<ExLazyFrame,
     Ok(ExLazyFrame::new(lf.filter(expr)))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_sort_with(
     data: ExLazyFrame,
     expressions: Vec<ExExpr>,
     directions: Vec<bool>,
     maintain_order: bool,
     nulls_last: bool,
+    limit: Option<IdxSize>,
 ) -> Result<ExLazyFrame, ExplorerError> {
     let exprs = ex_expr_to_exprs(expressions);
     let sort_options = SortMultipleOptions::new()
@@ -153,10 +205,16 @@ pub fn lf_sort_with(
 
     let ldf = data.clone_inner().sort_by_exprs(exprs, sort_options);
 
+    // The slice is pushed down into the sort, which then only keeps the top rows.
+    let ldf = match limit {
+        Some(limit) => ldf.limit(limit),
+        None => ldf,
+    };
+
     Ok(ExLazyFrame::new(ldf))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_grouped_sort_with(
     data: ExLazyFrame,
     expressions: Vec<ExExpr>,
@@ -174,7 +232,7 @@ pub fn lf_grouped_sort_with(
     Ok(ExLazyFrame::new(ldf))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_distinct(
     data: ExLazyFrame,
     subset: Vec<String>,
@@ -190,7 +248,7 @@ pub fn lf_distinct(
     }
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_mutate_with(
     data: ExLazyFrame,
     columns: Vec<ExExpr>,
@@ -206,7 +264,7 @@ pub fn lf_mutate_with(
     Ok(ExLazyFrame::new(ldf))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_summarise_with(
     data: ExLazyFrame,
     groups: Vec<ExExpr>,
@@ -230,7 +288,7 @@ pub fn lf_summarise_with(
     Ok(ExLazyFrame::new(new_lf))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_rename_columns(
     data: ExLazyFrame,
     renames: Vec<(&str, &str)>,
@@ -241,7 +299,7 @@ pub fn lf_rename_columns(
     Ok(ExLazyFrame::new(df.rename(existing, new, true)))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_drop_nils(
     data: ExLazyFrame,
     subset: Option<Vec<ExExpr>>,
@@ -252,7 +310,7 @@ pub fn lf_drop_nils(
     Ok(ExLazyFrame::new(ldf.drop_nulls(columns)))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_pivot_longer(
     data: ExLazyFrame,
     id_vars: Vec<String>,
@@ -278,7 +336,7 @@ fn to_lazy_selectors(values: Vec<String>) -> Vec<Selector> {
         .collect::<Vec<Selector>>()
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_join(
     data: ExLazyFrame,
     other: ExLazyFrame,
@@ -324,7 +382,7 @@ pub fn lf_join(
     Ok(ExLazyFrame::new(new_ldf))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_concat_rows(lazy_frames: Vec<ExLazyFrame>) -> Result<ExLazyFrame, ExplorerError> {
     let inputs: Vec<LazyFrame> = lazy_frames.iter().map(|lf| lf.clone_inner()).collect();
     let union_args = UnionArgs::default();
@@ -333,7 +391,7 @@ pub fn lf_concat_rows(lazy_frames: Vec<ExLazyFrame>) -> Result<ExLazyFrame, Expl
     Ok(ExLazyFrame::new(out_df))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn lf_concat_columns(ldfs: Vec<ExLazyFrame>) -> Result<ExLazyFrame, ExplorerError> {
     let mut previous_names = PlHashSet::new();
 
@@ -376,7 +434,7 @@ pub fn lf_concat_columns(ldfs: Vec<ExLazyFrame>) -> Result<ExLazyFrame, Explorer
     Ok(ExLazyFrame::new(out_ldf))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn lf_sql(
     lf: ExLazyFrame,
     sql_string: &str,
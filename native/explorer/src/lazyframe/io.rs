@@ -1,13 +1,42 @@
+use explorer_macros::nif;
 use polars::prelude::*;
 use std::fs::File;
 use std::io::BufWriter;
 use std::num::NonZeroUsize;
 
-use crate::dataframe::io::schema_from_dtypes_pairs;
+use crate::cancellation::{self, ExCancelTokenRef};
+use crate::dataframe::io::{schema_from_dtypes_pairs, write_csv, write_parquet};
 use crate::datatypes::{ExParquetCompression, ExQuoteStyle, ExS3Entry, ExSeriesDtype};
+use crate::local_message::with_progress;
 use crate::{ExLazyFrame, ExplorerError};
+use rustler::{LocalPid, ResourceArc, Term};
 
-#[rustler::nif]
+// Scans only read the file metadata, or infer the CSV schema, once the
+// schema is first needed. Resolving it here keeps that IO on a DirtyIo
+// scheduler: the result is cached in the scan, so `lf_names` and
+// `lf_dtypes` do not touch the file again from a regular scheduler.
+fn resolve_schema(mut lf: LazyFrame) -> Result<ExLazyFrame, ExplorerError> {
+    lf.collect_schema()?;
+
+    Ok(ExLazyFrame::new(lf))
+}
+
+// Streaming sinks write the file on their own, so they cannot report
+// progress.
+fn ensure_reportable(
+    streaming: bool,
+    progress: &Option<(LocalPid, Term)>,
+) -> Result<(), ExplorerError> {
+    if streaming && progress.is_some() {
+        return Err(ExplorerError::Other(
+            "progress cannot be reported on streaming writes".into(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[nif(schedule = "DirtyIo", cpu_variant)]
 pub fn lf_from_parquet(
     filename: &str,
     stop_after_n_rows: Option<usize>,
@@ -26,12 +55,12 @@ pub fn lf_from_parquet(
 
     let lf = LazyFrame::scan_parquet(filename, options)?.select(cols);
 
-    Ok(ExLazyFrame::new(lf))
+    resolve_schema(lf)
 }
 
 // When we have more cloud entries, we could accept an Enum.
 #[cfg(feature = "aws")]
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", cpu_variant)]
 pub fn lf_from_parquet_cloud(
     ex_entry: ExS3Entry,
     stop_after_n_rows: Option<usize>,
@@ -52,11 +81,11 @@ pub fn lf_from_parquet_cloud(
         .with_streaming(true)
         .select(cols);
 
-    Ok(ExLazyFrame::new(lf))
+    resolve_schema(lf)
 }
 
 #[cfg(not(feature = "aws"))]
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", cpu_variant)]
 pub fn lf_from_parquet_cloud(
     _ex_entry: ExS3Entry,
     _stop_after_n_rows: Option<usize>,
@@ -67,13 +96,17 @@ pub fn lf_from_parquet_cloud(
         Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
 }
 
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", measure)]
 pub fn lf_to_parquet(
     data: ExLazyFrame,
     filename: &str,
     ex_compression: ExParquetCompression,
     streaming: bool,
+    cancel_token: Option<ResourceArc<ExCancelTokenRef>>,
+    progress: Option<(LocalPid, Term)>,
 ) -> Result<(), ExplorerError> {
+    cancellation::ensure_cancellable(streaming, &cancel_token)?;
+    ensure_reportable(streaming, &progress)?;
     let compression = ParquetCompression::try_from(ex_compression)?;
 
     let lf = data.clone_inner();
@@ -91,21 +124,20 @@ pub fn lf_to_parquet(
             .sink_parquet(&filename, options, None)?;
         Ok(())
     } else {
-        let mut df = lf.collect()?;
-
-        let file = File::create(filename)?;
-        let mut buf_writer = BufWriter::new(file);
+        with_progress(progress, |report| {
+            let df = cancellation::collect(lf, cancel_token)?;
 
-        ParquetWriter::new(&mut buf_writer)
-            .with_compression(compression)
-            .finish(&mut df)?;
+            let file = File::create(filename)?;
+            let mut buf_writer = BufWriter::new(file);
+            let writer = ParquetWriter::new(&mut buf_writer).with_compression(compression);
 
-        Ok(())
+            write_parquet(&df, writer, report)
+        })
     }
 }
 
 #[cfg(feature = "aws")]
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo")]
 pub fn lf_to_parquet_cloud(
     data: ExLazyFrame,
     ex_entry: ExS3Entry,
@@ -129,7 +161,7 @@ pub fn lf_to_parquet_cloud(
 }
 
 #[cfg(not(feature = "aws"))]
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo")]
 pub fn lf_to_parquet_cloud(
     _data: ExLazyFrame,
     _ex_entry: ExS3Entry,
@@ -140,20 +172,23 @@ pub fn lf_to_parquet_cloud(
         Please read the section about precompilation in our README.md: https://github.com/elixir-explorer/explorer#precompilation".to_string()))
 }
 
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", cpu_variant)]
 pub fn lf_from_ipc(filename: &str) -> Result<ExLazyFrame, ExplorerError> {
     let lf = LazyFrame::scan_ipc(filename, Default::default())?;
 
-    Ok(ExLazyFrame::new(lf))
+    resolve_schema(lf)
 }
 
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", measure)]
 pub fn lf_to_ipc(
     data: ExLazyFrame,
     filename: &str,
     compression: Option<&str>,
     streaming: bool,
+    cancel_token: Option<ResourceArc<ExCancelTokenRef>>,
 ) -> Result<(), ExplorerError> {
+    cancellation::ensure_cancellable(streaming, &cancel_token)?;
+
     // Select the compression algorithm.
     let compression = match compression {
         Some("lz4") => Some(IpcCompression::LZ4),
@@ -172,7 +207,7 @@ pub fn lf_to_ipc(
             .sink_ipc(filename, options, None)?;
         Ok(())
     } else {
-        let mut df = lf.collect()?;
+        let mut df = cancellation::collect(lf, cancel_token)?;
         let file = File::create(filename)?;
         let mut buf_writer = BufWriter::new(file);
         IpcWriter::new(&mut buf_writer)
@@ -183,7 +218,7 @@ pub fn lf_to_ipc(
 }
 
 #[cfg(feature = "aws")]
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo")]
 pub fn lf_to_ipc_cloud(
     data: ExLazyFrame,
     ex_entry: ExS3Entry,
@@ -208,7 +243,7 @@ pub fn lf_to_ipc_cloud(
     Ok(())
 }
 
-#[rustler::nif]
+#[nif(schedule = "DirtyIo", cpu_variant)]
 #[allow(clippy::too_many_arguments)]
 pub fn lf_from_csv(
     filename: &str,
@@ -247,10 +282,11 @@ pub fn lf_from_csv(
         .with_eol_char(eol_delimiter.unwrap_or(b'\n'))
         .finish()?;
 
-    Ok(ExLazyFrame::new(df))
+    resolve_schema(df)
 }
 
-#[rustler::nif(schedule = "DirtyIo")]
+#[nif(schedule = "DirtyIo", measure)]
+#[allow(clippy::too_many_arguments)]
 pub fn lf_to_csv(
     data: ExLazyFrame,
     filename: &str,
@@ -258,7 +294,12 @@ pub fn lf_to_csv(
     delimiter: u8,
     quote_style: ExQuoteStyle,
     streaming: bool,
+    cancel_token: Option<ResourceArc<ExCancelTokenRef>>,
+    progress: Option<(LocalPid, Term)>,
 ) -> Result<(), ExplorerError> {
+    cancellation::ensure_cancellable(streaming, &cancel_token)?;
+    ensure_reportable(streaming, &progress)?;
+
     let lf = data.clone_inner();
     if streaming {
         let serialize_options = SerializeOptions {
@@ -277,21 +318,22 @@ pub fn lf_to_csv(
             .sink_csv(filename, options, None)?;
         Ok(())
     } else {
-        let df = lf.collect()?;
-        let file = File::create(filename)?;
-        let mut buf_writer = BufWriter::new(file);
-
-        CsvWriter::new(&mut buf_writer)
-            .include_header(include_headers)
-            .with_separator(delimiter)
-            .with_quote_style(quote_style.into())
-            .finish(&mut df.clone())?;
-        Ok(())
+        with_progress(progress, |report| {
+            let df = cancellation::collect(lf, cancel_token)?;
+            let file = File::create(filename)?;
+            let mut buf_writer = BufWriter::new(file);
+            let writer = CsvWriter::new(&mut buf_writer)
+                .include_header(include_headers)
+                .with_separator(delimiter)
+                .with_quote_style(quote_style.into());
+
+            write_csv(&df, writer, report)
+        })
     }
 }
 
 #[cfg(feature = "ndjson")]
-#[rustler::nif]
+#[nif(schedule = "DirtyIo", cpu_variant)]
 pub fn lf_from_ndjson(
     filename: String,
     infer_schema_length: Option<usize>,
@@ -305,11 +347,11 @@ pub fn lf_from_ndjson(
         .with_batch_size(Some(batch_size))
         .finish()?;
 
-    Ok(ExLazyFrame::new(lf))
+    resolve_schema(lf)
 }
 
 #[cfg(not(feature = "ndjson"))]
-#[rustler::nif]
+#[nif(cpu_variant)]
 pub fn lf_from_ndjson(
     _filename: &str,
     _infer_schema_length: Option<usize>,
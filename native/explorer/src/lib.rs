@@ -16,6 +16,7 @@ static GLOBAL: MiMalloc = MiMalloc;
 #[cfg(feature = "cloud")]
 mod cloud_writer;
 
+mod cancellation;
 mod dataframe;
 mod datatypes;
 mod encoding;
@@ -23,7 +24,11 @@ mod error;
 mod expressions;
 mod lazyframe;
 mod local_message;
+mod memory;
+mod native_stats;
 mod series;
+mod string_cache;
+mod thread_pool;
 
 pub use datatypes::{
     ExDataFrame, ExDataFrameRef, ExExpr, ExExprRef, ExLazyFrame, ExLazyFrameRef, ExSeries,
@@ -65,6 +70,10 @@ mod atoms {
         coef,
         exp,
         sign,
+        chunk,
+        done,
+        group,
+        progress,
     }
 }
 
@@ -1,5 +1,8 @@
+use crate::error::catch_panic;
+use crate::{atoms, ExplorerError};
+use explorer_macros::nif;
 use rustler::env::SavedTerm;
-use rustler::{Env, LocalPid, OwnedEnv, Resource, ResourceArc, Term};
+use rustler::{Encoder, Env, LocalPid, OwnedEnv, Resource, ResourceArc, Term};
 
 pub struct LocalMessage {
     pid: LocalPid,
@@ -31,12 +34,91 @@ impl Resource for LocalMessage {
     }
 }
 
-#[rustler::nif]
+#[nif]
 pub fn message_on_gc(pid: LocalPid, term: Term<'_>) -> ResourceArc<LocalMessage> {
     LocalMessage::new(pid, term).into()
 }
 
-#[rustler::nif]
+#[nif]
 pub fn is_message_on_gc(term: Term<'_>) -> bool {
     term.decode::<ResourceArc<LocalMessage>>().is_ok()
 }
+
+// Sends `{reference, message}` tuples to a process, see `send_tagged`.
+pub struct TaggedSender {
+    pid: LocalPid,
+    env: OwnedEnv,
+    // Kept apart from `env`, which is cleared after every message.
+    _reference_env: OwnedEnv,
+    reference: SavedTerm,
+}
+
+impl TaggedSender {
+    // Each message is built in a fresh environment, which is cleared once
+    // the message is sent. If building the message fails, the process
+    // gets `{reference, {:error, reason}}` instead and the error is returned.
+    pub fn send<F>(&mut self, fun: F) -> Result<(), ExplorerError>
+    where
+        F: for<'a> FnOnce(Env<'a>) -> Result<Term<'a>, ExplorerError>,
+    {
+        let reference = &self.reference;
+        let mut result = Ok(());
+
+        self.env
+            .send_and_clear(&self.pid, |env| {
+                let reference = reference.load(env);
+
+                match fun(env) {
+                    Ok(message) => (reference, message).encode(env),
+                    Err(error) => {
+                        let message = (rustler::types::atom::error(), error.encode(env));
+                        result = Err(error);
+                        (reference, message).encode(env)
+                    }
+                }
+            })
+            .map_err(|_| ExplorerError::Other("cannot send message to process".into()))?;
+
+        result
+    }
+}
+
+// `OwnedEnv::send_and_clear` cannot be called from threads managed by the VM,
+// which includes dirty schedulers. So `fun` runs in a separate thread, and we
+// wait for it to finish before returning its result. Panics in that thread
+// are returned as errors, like the ones of the NIF itself.
+pub fn send_tagged<T, F>(pid: LocalPid, reference: Term, fun: F) -> Result<T, ExplorerError>
+where
+    T: Send,
+    F: FnOnce(&mut TaggedSender) -> Result<T, ExplorerError> + Send,
+{
+    let reference_env = OwnedEnv::new();
+    let reference = reference_env.save(reference);
+    let mut sender = TaggedSender {
+        pid,
+        env: OwnedEnv::new(),
+        _reference_env: reference_env,
+        reference,
+    };
+
+    std::thread::scope(|scope| scope.spawn(move || catch_panic(|| fun(&mut sender))).join())
+        .map_err(|_| ExplorerError::Other("cannot send messages to process".into()))?
+}
+
+// Runs `fun` with a function that reports progress, given the number of rows
+// read or written so far. When a process is given, it gets
+// `{reference, {:progress, rows}}` for each report. Otherwise reports are
+// ignored, so callers report the same way whether progress was asked or not.
+pub fn with_progress<T, F>(progress: Option<(LocalPid, Term)>, fun: F) -> Result<T, ExplorerError>
+where
+    T: Send,
+    F: FnOnce(&mut dyn FnMut(usize) -> Result<(), ExplorerError>) -> Result<T, ExplorerError>
+        + Send,
+{
+    match progress {
+        Some((pid, reference)) => send_tagged(pid, reference, |sender| {
+            fun(&mut |rows| sender.send(|env| Ok((atoms::progress(), rows).encode(env))))
+        }),
+        None => fun(&mut |_rows| Ok(())),
+    }
+}
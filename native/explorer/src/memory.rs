@@ -0,0 +1,59 @@
+use crate::ExplorerError;
+use explorer_macros::nif;
+use rustler::NifMap;
+
+// Memory allocated by Polars lives outside of the BEAM heaps, so it does
+// not show up in `:erlang.memory/0`. These numbers come from MiMalloc and
+// are in bytes. "Allocated" is the memory committed by the allocator.
+#[derive(NifMap)]
+pub struct ExMemoryStats {
+    resident: usize,
+    peak_resident: usize,
+    allocated: usize,
+    peak_allocated: usize,
+}
+
+#[cfg(not(any(
+    all(windows, target_env = "gnu"),
+    all(target_os = "linux", target_env = "musl")
+)))]
+#[nif]
+pub fn memory_stats() -> Result<ExMemoryStats, ExplorerError> {
+    let mut elapsed = 0;
+    let mut user_time = 0;
+    let mut system_time = 0;
+    let mut page_faults = 0;
+    let mut stats = ExMemoryStats {
+        resident: 0,
+        peak_resident: 0,
+        allocated: 0,
+        peak_allocated: 0,
+    };
+
+    unsafe {
+        libmimalloc_sys::mi_process_info(
+            &mut elapsed,
+            &mut user_time,
+            &mut system_time,
+            &mut stats.resident,
+            &mut stats.peak_resident,
+            &mut stats.allocated,
+            &mut stats.peak_allocated,
+            &mut page_faults,
+        );
+    }
+
+    Ok(stats)
+}
+
+// MiMalloc is not used on these targets, see `lib.rs`.
+#[cfg(any(
+    all(windows, target_env = "gnu"),
+    all(target_os = "linux", target_env = "musl")
+))]
+#[nif]
+pub fn memory_stats() -> Result<ExMemoryStats, ExplorerError> {
+    Err(ExplorerError::Other(
+        "memory statistics are not available on this target".into(),
+    ))
+}
@@ -0,0 +1,128 @@
+use crate::{ExDataFrame, ExplorerError};
+use explorer_macros::nif;
+use rustler::NifMap;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+// Per-NIF timings, so the time spent inside Explorer can be told apart
+// from the rest of a request. Recording is off by default and costs a
+// single atomic load per call while disabled.
+//
+// NIFs are measured by declaring them with `#[nif(measure)]`, or with
+// `#[nif(measure(rows = arg))]` to count the rows of an argument instead
+// of the result. Only the NIFs where the work of a request usually goes
+// are measured:
+//
+// - `lf_compute` and `lf_fetch`, which run lazy queries;
+// - the `lf_to_*` file writers, which also run the query;
+// - the `df_from_*`, `df_load_*`, `df_to_*` and `df_dump_*` readers and
+//   writers for CSV, NDJSON, Parquet, IPC and IPC streams, except the
+//   cloud writers;
+// - `df_sort_by`, `df_sort_with`, `df_unique`, `df_upsert`,
+//   `df_concat_rows`, `df_pivot_wider`, `df_transpose`, `df_describe`,
+//   `df_correlation`, `df_covariance` and `df_column_stats`.
+//
+// Readers and queries record the rows they return, the `df_*` writers the
+// rows they write. The `lf_to_*` writers report no rows, since streaming
+// writes never see the whole result.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static STATS: Mutex<BTreeMap<&'static str, Stat>> = Mutex::new(BTreeMap::new());
+
+#[derive(Default)]
+struct Stat {
+    calls: u64,
+    total_ns: u64,
+    max_ns: u64,
+    rows: Option<u64>,
+}
+
+#[derive(NifMap)]
+pub struct ExNativeStat {
+    name: String,
+    calls: u64,
+    total_ns: u64,
+    max_ns: u64,
+    rows: Option<u64>,
+}
+
+/// The number of rows a NIF processed, when it can be told.
+pub trait Rows {
+    fn rows(&self) -> Option<usize>;
+}
+
+impl Rows for ExDataFrame {
+    fn rows(&self) -> Option<usize> {
+        Some(self.height())
+    }
+}
+
+impl Rows for () {
+    fn rows(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// Runs `fun` and, when stats are enabled, records its wall time under
+/// `name` along with the number of rows returned by `rows`.
+pub fn measure<T>(
+    name: &'static str,
+    rows: impl FnOnce(&T) -> Option<usize>,
+    fun: impl FnOnce() -> Result<T, ExplorerError>,
+) -> Result<T, ExplorerError> {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return fun();
+    }
+
+    let start = Instant::now();
+    let result = fun();
+    let elapsed = start.elapsed().as_nanos() as u64;
+    let rows = result.as_ref().ok().and_then(rows);
+
+    if let Ok(mut stats) = STATS.lock() {
+        let stat = stats.entry(name).or_default();
+        stat.calls += 1;
+        stat.total_ns += elapsed;
+        stat.max_ns = stat.max_ns.max(elapsed);
+
+        if let Some(rows) = rows {
+            *stat.rows.get_or_insert(0) += rows as u64;
+        }
+    }
+
+    result
+}
+
+#[nif]
+pub fn native_stats_enable(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed)
+}
+
+#[nif]
+pub fn native_stats() -> Result<Vec<ExNativeStat>, ExplorerError> {
+    let stats = STATS
+        .lock()
+        .map_err(|_| ExplorerError::Internal("native stats lock is poisoned".into()))?;
+
+    Ok(stats
+        .iter()
+        .map(|(name, stat)| ExNativeStat {
+            name: name.to_string(),
+            calls: stat.calls,
+            total_ns: stat.total_ns,
+            max_ns: stat.max_ns,
+            rows: stat.rows,
+        })
+        .collect())
+}
+
+#[nif]
+pub fn native_stats_reset() -> Result<(), ExplorerError> {
+    STATS
+        .lock()
+        .map_err(|_| ExplorerError::Internal("native stats lock is poisoned".into()))?
+        .clear();
+
+    Ok(())
+}
@@ -1,49 +1,59 @@
 use crate::{
+    atoms,
     datatypes::{
-        ex_naive_datetime_to_timestamp, ExCorrelationMethod, ExDate, ExDecimal, ExNaiveDateTime,
-        ExRankMethod, ExSeriesDtype, ExTime, ExTimeUnit, ExValidValue,
+        ex_datetime_to_timestamp, ex_naive_datetime_to_timestamp, ExCategoricalOrdering,
+        ExCorrelationMethod, ExDate, ExDateTime, ExDecimal, ExFrequenciesSort, ExNaiveDateTime,
+        ExNonExistent, ExRankMethod, ExSeriesDtype, ExTime, ExTimeUnit, ExValidValue,
     },
-    encoding, ExDataFrame, ExSeries, ExplorerError,
+    encoding,
+    local_message::send_tagged,
+    ExDataFrame, ExSeries, ExplorerError,
 };
 
 use encoding::encode_naive_datetime;
 
+use explorer_macros::nif;
+use polars::lazy::dsl;
 use polars::prelude::*;
 use polars_ops::chunked_array::cov::{cov, pearson_corr};
 use polars_ops::prelude::peaks::*;
-use rustler::{Binary, Encoder, Env, Term};
+use rustler::{Binary, Encoder, Env, LocalPid, Term};
 
+pub mod builder;
 pub mod from_list;
 pub mod log;
+pub mod range;
+pub mod sample;
+pub mod similarity;
 
-#[rustler::nif]
+#[nif]
 pub fn s_as_str(data: ExSeries) -> Result<String, ExplorerError> {
     Ok(format!("{:?}", data.resource.0))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn s_name(data: ExSeries) -> Result<String, ExplorerError> {
     Ok(data.name().to_string())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn s_rename(data: ExSeries, name: &str) -> Result<ExSeries, ExplorerError> {
     let mut s = data.clone_inner();
     s.rename(name.into());
     Ok(ExSeries::new(s))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn s_dtype(data: ExSeries) -> Result<ExSeriesDtype, ExplorerError> {
     ExSeriesDtype::try_from(data.dtype())
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_slice(series: ExSeries, offset: i64, length: usize) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(series.slice(offset, length)))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_concat(series_vec: Vec<ExSeries>) -> Result<ExSeries, ExplorerError> {
     let mut iter = series_vec.iter();
     let mut series = iter.next().unwrap().clone_inner();
@@ -55,7 +65,7 @@ pub fn s_concat(series_vec: Vec<ExSeries>) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(series))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_mask(series: ExSeries, filter: ExSeries) -> Result<ExSeries, ExplorerError> {
     if let Ok(ca) = filter.bool() {
         let series = series.filter(ca)?;
@@ -65,7 +75,7 @@ pub fn s_mask(series: ExSeries, filter: ExSeries) -> Result<ExSeries, ExplorerEr
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_add(data: ExSeries, other: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s = data.clone_inner();
     let s1 = other.clone_inner();
@@ -73,7 +83,7 @@ pub fn s_add(data: ExSeries, other: ExSeries) -> Result<ExSeries, ExplorerError>
     Ok(ExSeries::new(result?))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_subtract(lhs: ExSeries, rhs: ExSeries) -> Result<ExSeries, ExplorerError> {
     let left = lhs.clone_inner();
     let right = rhs.clone_inner();
@@ -81,7 +91,7 @@ pub fn s_subtract(lhs: ExSeries, rhs: ExSeries) -> Result<ExSeries, ExplorerErro
     Ok(ExSeries::new(result?))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_multiply(data: ExSeries, other: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s = data.clone_inner();
     let s1 = other.clone_inner();
@@ -89,7 +99,7 @@ pub fn s_multiply(data: ExSeries, other: ExSeries) -> Result<ExSeries, ExplorerE
     Ok(ExSeries::new(result?))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_divide(data: ExSeries, other: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s = data.clone_inner().cast(&DataType::Float64)?;
     let s1 = other.clone_inner().cast(&DataType::Float64)?;
@@ -97,12 +107,12 @@ pub fn s_divide(data: ExSeries, other: ExSeries) -> Result<ExSeries, ExplorerErr
     Ok(ExSeries::new(result?))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_quotient(data: ExSeries, other: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(checked_div(data, other)?))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_remainder(data: ExSeries, other: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s = data.clone_inner();
     let s1 = other.clone_inner();
@@ -131,22 +141,28 @@ fn checked_div(data: ExSeries, other: ExSeries) -> Result<Series, ExplorerError>
     }
 }
 
-#[rustler::nif]
+#[nif]
 pub fn s_head(series: ExSeries, length: Option<usize>) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(series.head(length)))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_tail(series: ExSeries, length: Option<usize>) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(series.tail(length)))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_shift(series: ExSeries, offset: i64) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(series.shift(offset)))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn s_pct_change(series: ExSeries, n: i64) -> Result<ExSeries, ExplorerError> {
+    let s = apply_lazy_expr(&series, |expr| expr.pct_change(n.lit()))?;
+    Ok(ExSeries::new(s))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_sort(
     series: ExSeries,
     descending: bool,
@@ -164,7 +180,7 @@ pub fn s_sort(
     Ok(ExSeries::new(series.sort_with(opts)?))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_argsort(
     series: ExSeries,
     descending: bool,
@@ -183,25 +199,72 @@ pub fn s_argsort(
     Ok(ExSeries::new(indices))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_distinct(series: ExSeries) -> Result<ExSeries, ExplorerError> {
     let unique = series.take(&series.arg_unique()?)?;
     Ok(ExSeries::new(unique))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_unordered_distinct(series: ExSeries) -> Result<ExSeries, ExplorerError> {
     let unique = series.unique()?;
     Ok(ExSeries::new(unique))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
-pub fn s_frequencies(series: ExSeries) -> Result<ExDataFrame, ExplorerError> {
+#[nif(schedule = "DirtyCpu")]
+pub fn s_is_duplicated(series: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s = apply_lazy_expr(&series, |expr| expr.is_duplicated())?;
+    Ok(ExSeries::new(s))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_is_unique(series: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s = apply_lazy_expr(&series, |expr| expr.is_unique())?;
+    Ok(ExSeries::new(s))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_frequencies(
+    series: ExSeries,
+    sort_by: ExFrequenciesSort,
+    descending: bool,
+    include_proportions: bool,
+) -> Result<ExDataFrame, ExplorerError> {
+    // Polars sorts by counts in descending order, so we only need
+    // to sort again (keeping ties in place) for the other orderings.
     let df = series.value_counts(true, true, "counts".into(), false)?;
+    let options = SortMultipleOptions::default()
+        .with_order_descending(descending)
+        .with_maintain_order(true);
+
+    let mut df = match sort_by {
+        ExFrequenciesSort::Counts if descending => df,
+        ExFrequenciesSort::Counts => df.sort(["counts"], options)?,
+        ExFrequenciesSort::Values => df.sort([series.name().clone()], options)?,
+    };
+
+    if include_proportions {
+        let total = series.len() as f64;
+        let proportions = df
+            .column("counts")?
+            .as_materialized_series()
+            .cast(&DataType::Float64)?
+            / total;
+
+        df.with_column(proportions.with_name("proportions".into()))?;
+    }
+
     Ok(ExDataFrame::new(df))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+// Unlike frequencies, the counts are returned in order of first appearance.
+#[nif(schedule = "DirtyCpu")]
+pub fn s_unique_counts(series: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s = apply_lazy_expr(&series, |expr| expr.unique_counts())?;
+    Ok(ExSeries::new(s))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_cut(
     series: ExSeries,
     bins: Vec<f64>,
@@ -222,28 +285,67 @@ pub fn s_cut(
         include_breaks,
     )?;
 
-    if include_breaks {
-        let mut cut_df = cut_series.struct_()?.clone().unnest();
+    binned_frame(
+        series,
+        cut_series,
+        break_point_label,
+        category_label,
+        include_breaks,
+    )
+}
+
+fn unique_quantile_breaks(series: &Series, quantiles: &[f64]) -> Result<Vec<f64>, ExplorerError> {
+    let sorted = series
+        .cast(&DataType::Float64)?
+        .sort(SortOptions::default())?;
+    let ca = sorted.f64()?;
+
+    let mut breaks = quantiles
+        .iter()
+        .map(|quantile| {
+            ca.quantile(*quantile, QuantileMethod::Linear)?
+                .ok_or_else(|| ExplorerError::Other("cannot compute quantile".into()))
+        })
+        .collect::<Result<Vec<f64>, ExplorerError>>()?;
 
-        let cut_df = cut_df.insert_column(0, series)?;
+    breaks.sort_by(|a, b| a.total_cmp(b));
+    breaks.dedup();
 
-        cut_df.set_column_names([
+    Ok(breaks)
+}
+
+// Both cut and qcut return a series of categories, or a series of structs
+// with the break points and categories when `include_breaks` is true.
+// Either way, we return a frame with the original values first.
+fn binned_frame(
+    series: Series,
+    binned: Series,
+    break_point_label: Option<&str>,
+    category_label: Option<&str>,
+    include_breaks: bool,
+) -> Result<ExDataFrame, ExplorerError> {
+    let category_label = category_label.unwrap_or("category");
+
+    let df = if include_breaks {
+        let mut df = binned.struct_()?.clone().unnest();
+        df.insert_column(0, series)?;
+        df.set_column_names([
             "values",
             break_point_label.unwrap_or("break_point"),
-            category_label.unwrap_or("category"),
+            category_label,
         ])?;
-
-        Ok(ExDataFrame::new(cut_df.clone()))
+        df
     } else {
-        let mut cut_df = DataFrame::new(vec![Column::from(series), Column::from(cut_series)])?;
-        cut_df.set_column_names(["values", category_label.unwrap_or("category")])?;
+        let mut df = DataFrame::new(vec![Column::from(series), Column::from(binned)])?;
+        df.set_column_names(["values", category_label])?;
+        df
+    };
 
-        Ok(ExDataFrame::new(cut_df.clone()))
-    }
+    Ok(ExDataFrame::new(df))
 }
 
 #[allow(clippy::too_many_arguments)]
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_qcut(
     series: ExSeries,
     quantiles: Vec<f64>,
@@ -255,43 +357,84 @@ pub fn s_qcut(
     include_breaks: bool,
 ) -> Result<ExDataFrame, ExplorerError> {
     let series = series.clone_inner();
-
-    let qcut_series: Series = qcut(
-        &series,
-        quantiles,
-        labels.map(|vec| vec.iter().map(|label| label.into()).collect()),
-        left_close,
-        allow_duplicates,
-        include_breaks,
-    )?;
-
-    if include_breaks {
-        let mut qcut_df = qcut_series.struct_()?.clone().unnest();
-        let qcut_df = qcut_df.insert_column(0, series)?;
-
-        qcut_df.set_column_names([
-            "values",
-            break_point_label.unwrap_or("break_point"),
-            category_label.unwrap_or("category"),
-        ])?;
-
-        Ok(ExDataFrame::new(qcut_df.clone()))
+    let labels = labels.map(|vec| vec.iter().map(|label| label.into()).collect());
+
+    // Polars keeps duplicated quantiles around when they are allowed, which
+    // results in repeated categories. So we drop them and cut on the unique
+    // break points instead, which also means labels must match those.
+    let qcut_series: Series = if allow_duplicates && series.null_count() < series.len() {
+        let breaks = unique_quantile_breaks(&series, &quantiles)?;
+        cut(&series, breaks, labels, left_close, include_breaks)?
     } else {
-        let mut qcut_df = DataFrame::new(vec![Column::from(series), Column::from(qcut_series)])?;
-        qcut_df.set_column_names(["values", category_label.unwrap_or("category")])?;
+        qcut(
+            &series,
+            quantiles,
+            labels,
+            left_close,
+            allow_duplicates,
+            include_breaks,
+        )?
+    };
 
-        Ok(ExDataFrame::new(qcut_df.clone()))
-    }
+    binned_frame(
+        series,
+        qcut_series,
+        break_point_label,
+        category_label,
+        include_breaks,
+    )
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_slice_by_indices(series: ExSeries, indices: Vec<u32>) -> Result<ExSeries, ExplorerError> {
     let idx = UInt32Chunked::from_vec("idx".into(), indices);
     let s1 = series.take(&idx)?;
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+// The new values are first gathered into their target positions, and then
+// merged with the original series, so this works for any dtype.
+#[nif(schedule = "DirtyCpu")]
+pub fn s_scatter(
+    series: ExSeries,
+    indices: Vec<u32>,
+    values: ExSeries,
+) -> Result<ExSeries, ExplorerError> {
+    let broadcast = values.len() == 1;
+
+    if !broadcast && values.len() != indices.len() {
+        return Err(ExplorerError::Other(format!(
+            "scatter/3 expects as many values as indices, got {} values for {} indices",
+            values.len(),
+            indices.len()
+        )));
+    }
+
+    let mut positions: Vec<Option<u32>> = vec![None; series.len()];
+
+    for (position, index) in indices.into_iter().enumerate() {
+        match positions.get_mut(index as usize) {
+            Some(slot) => *slot = Some(if broadcast { 0 } else { position as u32 }),
+            None => {
+                return Err(ExplorerError::Other(format!(
+                    "scatter/3 cannot set index {index} on a series of length {}",
+                    series.len()
+                )))
+            }
+        }
+    }
+
+    let positions = UInt32Chunked::from_iter_options("idx".into(), positions.into_iter());
+    let scattered = values
+        .cast(series.dtype())?
+        .take(&positions)?
+        .zip_with(&positions.is_not_null(), &series)?
+        .with_name(series.name().clone());
+
+    Ok(ExSeries::new(scattered))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_slice_by_series(series: ExSeries, indices: ExSeries) -> Result<ExSeries, ExplorerError> {
     match indices.strict_cast(&DataType::UInt32) {
         Ok(casted) => {
@@ -309,37 +452,37 @@ pub fn s_slice_by_series(series: ExSeries, indices: ExSeries) -> Result<ExSeries
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_is_null(series: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(series.is_null().into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_is_not_null(series: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(series.is_not_null().into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_is_finite(series: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(series.is_finite()?.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_is_infinite(series: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(series.is_infinite()?.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_is_nan(series: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(series.is_nan()?.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_at_every(series: ExSeries, n: usize) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(series.gather_every(n, 0)))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_series_equal(
     series: ExSeries,
     other: ExSeries,
@@ -354,49 +497,49 @@ pub fn s_series_equal(
     Ok(result)
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_equal(lhs: ExSeries, rhs: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(
         lhs.clone_inner().equal(&rhs.clone_inner())?.into_series(),
     ))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_not_equal(data: ExSeries, rhs: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s = data.clone_inner();
     let s1 = rhs.clone_inner();
     Ok(ExSeries::new(s.not_equal(&s1)?.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_greater(data: ExSeries, rhs: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s = data.clone_inner();
     let s1 = rhs.clone_inner();
     Ok(ExSeries::new(s.gt(&s1)?.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_greater_equal(data: ExSeries, rhs: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s = data.clone_inner();
     let s1 = rhs.clone_inner();
     Ok(ExSeries::new(s.gt_eq(&s1)?.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_less(data: ExSeries, rhs: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s = data.clone_inner();
     let s1 = rhs.clone_inner();
     Ok(ExSeries::new(s.lt(&s1)?.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_less_equal(data: ExSeries, rhs: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s = data.clone_inner();
     let s1 = rhs.clone_inner();
     Ok(ExSeries::new(s.lt_eq(&s1)?.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_in(s: ExSeries, rhs: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s = match s.dtype() {
         DataType::Boolean
@@ -404,6 +547,7 @@ pub fn s_in(s: ExSeries, rhs: ExSeries) -> Result<ExSeries, ExplorerError> {
         | DataType::Int16
         | DataType::Int32
         | DataType::Int64
+        | DataType::Int128
         | DataType::UInt8
         | DataType::UInt16
         | DataType::UInt32
@@ -461,29 +605,34 @@ pub fn s_in(s: ExSeries, rhs: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(s.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_and(lhs: ExSeries, rhs: ExSeries) -> Result<ExSeries, ExplorerError> {
     let and = lhs.bool()? & rhs.bool()?;
     Ok(ExSeries::new(and.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_or(lhs: ExSeries, rhs: ExSeries) -> Result<ExSeries, ExplorerError> {
     let or = lhs.bool()? | rhs.bool()?;
     Ok(ExSeries::new(or.into_series()))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn s_size(series: ExSeries) -> Result<usize, ExplorerError> {
     Ok(series.len())
 }
 
-#[rustler::nif]
+#[nif]
 pub fn s_nil_count(series: ExSeries) -> Result<usize, ExplorerError> {
     Ok(series.null_count())
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn s_rechunk(series: ExSeries) -> Result<ExSeries, ExplorerError> {
+    Ok(ExSeries::new(series.rechunk()))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_fill_missing_with_strategy(
     series: ExSeries,
     strategy: &str,
@@ -500,26 +649,26 @@ pub fn s_fill_missing_with_strategy(
     Ok(ExSeries::new(series.fill_null(strat)?))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_fill_missing_with_atom(series: ExSeries, atom: &str) -> Result<ExSeries, ExplorerError> {
     let value = cast_str_to_f64(atom);
     let s = series.f64()?.fill_null_with_values(value)?.into_series();
     Ok(ExSeries::new(s))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_fill_missing_with_int(series: ExSeries, integer: i64) -> Result<ExSeries, ExplorerError> {
     let s = series.i64()?.fill_null_with_values(integer)?.into_series();
     Ok(ExSeries::new(s))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_fill_missing_with_float(series: ExSeries, float: f64) -> Result<ExSeries, ExplorerError> {
     let s = series.f64()?.fill_null_with_values(float)?.into_series();
     Ok(ExSeries::new(s))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_fill_missing_with_bin(
     series: ExSeries,
     binary: Binary,
@@ -548,7 +697,7 @@ pub fn s_fill_missing_with_bin(
     Ok(ExSeries::new(s))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_fill_missing_with_date(series: ExSeries, date: ExDate) -> Result<ExSeries, ExplorerError> {
     let s = series
         .date()?
@@ -558,7 +707,7 @@ pub fn s_fill_missing_with_date(series: ExSeries, date: ExDate) -> Result<ExSeri
     Ok(ExSeries::new(s))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_fill_missing_with_datetime(
     series: ExSeries,
     ex_naive_datetime: ExNaiveDateTime,
@@ -578,7 +727,7 @@ pub fn s_fill_missing_with_datetime(
     Ok(ExSeries::new(s))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_fill_missing_with_boolean(
     series: ExSeries,
     boolean: bool,
@@ -587,23 +736,19 @@ pub fn s_fill_missing_with_boolean(
     Ok(ExSeries::new(s))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_fill_missing_with_decimal(
-    _series: ExSeries,
-    _decimal: ExDecimal,
+    series: ExSeries,
+    decimal: ExDecimal,
 ) -> Result<ExSeries, ExplorerError> {
-    // We need to make sure that the series dtype is aligned with the decimal's scale.
-    // let s = series
-    //     .decimal()?
-    //     .fill_null_with_values(decimal.signed_coef())?
-    //     .into_series();
-    // Ok(ExSeries::new(s))
-    Err(ExplorerError::Other(
-        "fill_missing/2 with decimals is not supported yet by Polars".to_string(),
-    ))
+    // We cast the value to the series dtype, so the scale of the result is preserved.
+    let dtype = series.dtype().clone();
+    let s = apply_lazy_expr(&series, |expr| expr.fill_null(decimal.lit().cast(dtype)))?;
+
+    Ok(ExSeries::new(s))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_window_sum(
     series: ExSeries,
     window_size: usize,
@@ -611,12 +756,20 @@ pub fn s_window_sum(
     min_periods: Option<usize>,
     center: bool,
 ) -> Result<ExSeries, ExplorerError> {
-    let opts = rolling_opts_fixed_window(window_size, weights, min_periods, center);
-    let s1 = series.rolling_sum(opts)?;
+    let s1 = apply_lazy_expr(&series, |expr| {
+        rolling_expr(
+            expr,
+            window_size,
+            weights,
+            min_periods,
+            center,
+            Expr::rolling_sum,
+        )
+    })?;
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_window_mean(
     series: ExSeries,
     window_size: usize,
@@ -624,12 +777,20 @@ pub fn s_window_mean(
     min_periods: Option<usize>,
     center: bool,
 ) -> Result<ExSeries, ExplorerError> {
-    let opts = rolling_opts_fixed_window(window_size, weights, min_periods, center);
-    let s1 = series.rolling_mean(opts)?;
+    let s1 = apply_lazy_expr(&series, |expr| {
+        rolling_expr(
+            expr,
+            window_size,
+            weights,
+            min_periods,
+            center,
+            Expr::rolling_mean,
+        )
+    })?;
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_window_median(
     series: ExSeries,
     window_size: usize,
@@ -637,21 +798,20 @@ pub fn s_window_median(
     min_periods: Option<usize>,
     center: bool,
 ) -> Result<ExSeries, ExplorerError> {
-    let opts = rolling_opts_fixed_window(window_size, weights, min_periods, center);
-    let s1 = series
-        .clone_inner()
-        .into_frame()
-        .lazy()
-        .select([col(series.name().clone()).rolling_median(opts)])
-        .collect()?
-        .column(series.name())?
-        .as_materialized_series()
-        .clone();
-
+    let s1 = apply_lazy_expr(&series, |expr| {
+        rolling_expr(
+            expr,
+            window_size,
+            weights,
+            min_periods,
+            center,
+            Expr::rolling_median,
+        )
+    })?;
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_window_max(
     series: ExSeries,
     window_size: usize,
@@ -659,12 +819,20 @@ pub fn s_window_max(
     min_periods: Option<usize>,
     center: bool,
 ) -> Result<ExSeries, ExplorerError> {
-    let opts = rolling_opts_fixed_window(window_size, weights, min_periods, center);
-    let s1 = series.rolling_max(opts)?;
+    let s1 = apply_lazy_expr(&series, |expr| {
+        rolling_expr(
+            expr,
+            window_size,
+            weights,
+            min_periods,
+            center,
+            Expr::rolling_max,
+        )
+    })?;
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_window_min(
     series: ExSeries,
     window_size: usize,
@@ -672,12 +840,20 @@ pub fn s_window_min(
     min_periods: Option<usize>,
     center: bool,
 ) -> Result<ExSeries, ExplorerError> {
-    let opts = rolling_opts_fixed_window(window_size, weights, min_periods, center);
-    let s1 = series.rolling_min(opts)?;
+    let s1 = apply_lazy_expr(&series, |expr| {
+        rolling_expr(
+            expr,
+            window_size,
+            weights,
+            min_periods,
+            center,
+            Expr::rolling_min,
+        )
+    })?;
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_window_standard_deviation(
     series: ExSeries,
     window_size: usize,
@@ -685,12 +861,45 @@ pub fn s_window_standard_deviation(
     min_periods: Option<usize>,
     center: bool,
 ) -> Result<ExSeries, ExplorerError> {
-    let opts = rolling_opts_fixed_window(window_size, weights, min_periods, center);
-    let s1 = series.rolling_std(opts)?;
+    let s1 = apply_lazy_expr(&series, |expr| {
+        rolling_expr(
+            expr,
+            window_size,
+            weights,
+            min_periods,
+            center,
+            Expr::rolling_std,
+        )
+        .cast(DataType::Float64)
+    })?;
     Ok(ExSeries::new(s1))
 }
 
-// Used for rolling functions - also see "expressions" module
+// Used for rolling functions - also see "expressions" module.
+// Both series and expressions go through the same expression, so
+// weights, min_periods and center behave the same way in both.
+pub fn rolling_expr<F>(
+    expr: Expr,
+    window_size: usize,
+    weights: Option<Vec<f64>>,
+    min_periods: Option<usize>,
+    center: bool,
+    fun: F,
+) -> Expr
+where
+    F: FnOnce(Expr, RollingOptionsFixedWindow) -> Expr,
+{
+    let opts = rolling_opts_fixed_window(window_size, weights, min_periods, center);
+
+    // Weighted windows are only supported on floats.
+    let expr = match opts.weights {
+        Some(_) => expr.cast(DataType::Float64),
+        None => expr,
+    };
+
+    fun(expr, opts)
+}
+
 pub fn rolling_opts_fixed_window(
     window_size: usize,
     weights: Option<Vec<f64>>,
@@ -702,18 +911,17 @@ pub fn rolling_opts_fixed_window(
     } else {
         window_size
     };
-    // let window_size_duration = Duration::new(window_size as i64);
 
     RollingOptionsFixedWindow {
         window_size,
-        weights,
+        weights: weights.filter(|weights| !weights.is_empty()),
         min_periods,
         center,
         ..Default::default()
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_ewm_mean(
     series: ExSeries,
     alpha: f64,
@@ -721,12 +929,12 @@ pub fn s_ewm_mean(
     min_periods: usize,
     ignore_nulls: bool,
 ) -> Result<ExSeries, ExplorerError> {
-    let opts = ewm_opts(alpha, adjust, min_periods, ignore_nulls);
+    let opts = ewm_opts(alpha, adjust, false, min_periods, ignore_nulls);
     let s1 = polars_ops::prelude::ewm_mean(&series, opts)?;
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_ewm_standard_deviation(
     series: ExSeries,
     alpha: f64,
@@ -735,18 +943,12 @@ pub fn s_ewm_standard_deviation(
     min_periods: usize,
     ignore_nulls: bool,
 ) -> Result<ExSeries, ExplorerError> {
-    let opts = EWMOptions {
-        alpha,
-        adjust,
-        bias,
-        min_periods,
-        ignore_nulls,
-    };
+    let opts = ewm_opts(alpha, adjust, bias, min_periods, ignore_nulls);
     let s1 = polars_ops::prelude::ewm_std(&series, opts)?;
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_ewm_variance(
     series: ExSeries,
     alpha: f64,
@@ -755,33 +957,75 @@ pub fn s_ewm_variance(
     min_periods: usize,
     ignore_nulls: bool,
 ) -> Result<ExSeries, ExplorerError> {
-    let opts = EWMOptions {
-        alpha,
-        adjust,
-        bias,
-        min_periods,
-        ignore_nulls,
-    };
+    let opts = ewm_opts(alpha, adjust, bias, min_periods, ignore_nulls);
     let s1 = polars_ops::prelude::ewm_var(&series, opts)?;
     Ok(ExSeries::new(s1))
 }
 
-pub fn ewm_opts(alpha: f64, adjust: bool, min_periods: usize, ignore_nulls: bool) -> EWMOptions {
+// Shared by series and expressions, so every EWM function forwards
+// adjust, bias, min_periods and ignore_nulls the same way.
+// `bias` is only meaningful for standard deviation and variance.
+pub fn ewm_opts(
+    alpha: f64,
+    adjust: bool,
+    bias: bool,
+    min_periods: usize,
+    ignore_nulls: bool,
+) -> EWMOptions {
     EWMOptions {
         alpha,
         adjust,
+        bias,
         min_periods,
         ignore_nulls,
-        ..Default::default()
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_to_list(env: Env, data: ExSeries) -> Result<Term, ExplorerError> {
     encoding::list_from_series(data, env)
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn s_struct_to_maps(env: Env, data: ExSeries, atom_keys: bool) -> Result<Term, ExplorerError> {
+    encoding::struct_series_to_maps(&data, atom_keys, env)
+}
+
+// Encodes the series in batches of `batch_size` and sends each one to `pid`
+// as `{ref, {:chunk, list}}`, followed by `{ref, :done}`. Each batch is built
+// in its own environment, so we never hold the whole list in memory at once.
+#[nif(schedule = "DirtyCpu")]
+pub fn s_to_list_chunked(
+    data: ExSeries,
+    batch_size: usize,
+    pid: LocalPid,
+    reference: Term,
+) -> Result<(), ExplorerError> {
+    if batch_size == 0 {
+        return Err(ExplorerError::Other(
+            "to_list_chunked expects a positive batch size".into(),
+        ));
+    }
+
+    send_tagged(pid, reference, |sender| {
+        let mut offset = 0;
+
+        while offset < data.len() {
+            let batch = ExSeries::new(data.slice(offset as i64, batch_size));
+
+            sender.send(|env| {
+                let list = encoding::list_from_series(batch, env)?;
+                Ok((atoms::chunk(), list).encode(env))
+            })?;
+
+            offset += batch_size;
+        }
+
+        sender.send(|env| Ok(atoms::done().encode(env)))
+    })
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_to_iovec(env: Env, series: ExSeries) -> Result<Term, ExplorerError> {
     if series.null_count() != 0 {
         Err(ExplorerError::Other(
@@ -792,7 +1036,62 @@ pub fn s_to_iovec(env: Env, series: ExSeries) -> Result<Term, ExplorerError> {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+// Returns the address and size in bytes of the values of the series, along
+// with the series that owns them. The memory stays valid for as long as
+// that series is alive, and must not be written to.
+#[nif(schedule = "DirtyCpu")]
+pub fn s_to_pointer(series: ExSeries) -> Result<(u64, usize, ExSeries), ExplorerError> {
+    if series.null_count() != 0 {
+        return Err(ExplorerError::Other(
+            "cannot invoke to_pointer on series with nils".into(),
+        ));
+    }
+
+    let series = series.rechunk();
+
+    let Some((pointer, size)) = values_buffer(&series) else {
+        return Err(ExplorerError::Other(format!(
+            "cannot get a pointer to series of dtype {}",
+            series.dtype()
+        )));
+    };
+
+    Ok((pointer, size, ExSeries::new(series)))
+}
+
+// Returns the address and size in bytes of the values in the first chunk
+// of a numeric or temporal series.
+fn values_buffer(series: &Series) -> Option<(u64, usize)> {
+    let physical = series.to_physical_repr();
+
+    let buffer = match physical.dtype() {
+        DataType::Int8 => values_pointer(physical.i8().ok()?),
+        DataType::Int16 => values_pointer(physical.i16().ok()?),
+        DataType::Int32 => values_pointer(physical.i32().ok()?),
+        DataType::Int64 => values_pointer(physical.i64().ok()?),
+        DataType::Int128 => values_pointer(physical.i128().ok()?),
+        DataType::UInt8 => values_pointer(physical.u8().ok()?),
+        DataType::UInt16 => values_pointer(physical.u16().ok()?),
+        DataType::UInt32 => values_pointer(physical.u32().ok()?),
+        DataType::UInt64 => values_pointer(physical.u64().ok()?),
+        DataType::Float32 => values_pointer(physical.f32().ok()?),
+        DataType::Float64 => values_pointer(physical.f64().ok()?),
+        _ => return None,
+    };
+
+    Some(buffer)
+}
+
+fn values_pointer<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> (u64, usize) {
+    let values: &[T::Native] = ca
+        .downcast_iter()
+        .next()
+        .map_or(&[][..], |array| array.values().as_slice());
+
+    (values.as_ptr() as u64, std::mem::size_of_val(values))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_sum(env: Env, s: ExSeries) -> Result<Term, ExplorerError> {
     match s.dtype() {
         DataType::Boolean => Ok(s.sum::<u32>()?.encode(env)),
@@ -802,85 +1101,162 @@ pub fn s_sum(env: Env, s: ExSeries) -> Result<Term, ExplorerError> {
         DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
             Ok(s.sum::<u64>()?.encode(env))
         }
+        DataType::Int128 => Ok(s.sum::<i128>()?.encode(env)),
         DataType::Float32 | DataType::Float64 => {
             Ok(encoding::term_from_float64(s.sum::<f64>()?, env))
         }
+        DataType::Decimal(_, _) => {
+            let decimal = s.decimal()?;
+            let sum = decimal.physical().sum().unwrap_or(0);
+            encoding::encode_decimal(sum, decimal.scale(), env)
+        }
+        DataType::Duration(time_unit) => {
+            let sum = s.duration()?.physical().sum().unwrap_or(0);
+            encoding::encode_duration(sum, *time_unit, env)
+        }
         dt => panic!("sum/1 not implemented for {dt:?}"),
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_min(env: Env, s: ExSeries) -> Result<Term, ExplorerError> {
     match s.dtype() {
         DataType::Int8 => Ok(s.min::<i8>()?.encode(env)),
         DataType::Int16 => Ok(s.min::<i16>()?.encode(env)),
         DataType::Int32 => Ok(s.min::<i32>()?.encode(env)),
         DataType::Int64 => Ok(s.min::<i64>()?.encode(env)),
+        DataType::Int128 => Ok(s.min::<i128>()?.encode(env)),
         DataType::UInt8 => Ok(s.min::<u8>()?.encode(env)),
         DataType::UInt16 => Ok(s.min::<u16>()?.encode(env)),
         DataType::UInt32 => Ok(s.min::<u32>()?.encode(env)),
         DataType::UInt64 => Ok(s.min::<u64>()?.encode(env)),
-        DataType::Float32 | DataType::Float64 | DataType::Decimal(_, _) => {
-            Ok(term_from_optional_float(s.min::<f64>()?, env))
+        DataType::Float32 | DataType::Float64 => Ok(term_from_optional_float(s.min::<f64>()?, env)),
+        DataType::Decimal(_, _) => {
+            let decimal = s.decimal()?;
+            match decimal.physical().min() {
+                Some(value) => encoding::encode_decimal(value, decimal.scale(), env),
+                None => Ok(rustler::types::atom::nil().to_term(env)),
+            }
         }
         DataType::Date => Ok(s.min::<i32>()?.map(ExDate::from).encode(env)),
         DataType::Time => Ok(s.min::<i64>()?.map(ExTime::from).encode(env)),
-        DataType::Datetime(unit, _) => Ok(s
+        DataType::Datetime(unit, None) => Ok(s
             .min::<i64>()?
             .map(|v| encode_naive_datetime(v, *unit, env).unwrap())
             .encode(env)),
+        DataType::Datetime(unit, Some(time_zone)) => {
+            let time_zone = encoding::parse_time_zone(time_zone)?;
+            match s.min::<i64>()? {
+                Some(v) => encoding::encode_datetime(v, *unit, time_zone, env),
+                None => Ok(rustler::types::atom::nil().to_term(env)),
+            }
+        }
+        // Enums are ordered by the position of their categories.
+        DataType::Enum(Some(mapping), _) => Ok(s
+            .categorical()?
+            .physical()
+            .min()
+            .map(|id| mapping.get(id))
+            .encode(env)),
         dt => panic!("min/1 not implemented for {dt:?}"),
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_max(env: Env, s: ExSeries) -> Result<Term, ExplorerError> {
     match s.dtype() {
         DataType::Int8 => Ok(s.max::<i8>()?.encode(env)),
         DataType::Int16 => Ok(s.max::<i16>()?.encode(env)),
         DataType::Int32 => Ok(s.max::<i32>()?.encode(env)),
         DataType::Int64 => Ok(s.max::<i64>()?.encode(env)),
+        DataType::Int128 => Ok(s.max::<i128>()?.encode(env)),
         DataType::UInt8 => Ok(s.max::<u8>()?.encode(env)),
         DataType::UInt16 => Ok(s.max::<u16>()?.encode(env)),
         DataType::UInt32 => Ok(s.max::<u32>()?.encode(env)),
         DataType::UInt64 => Ok(s.max::<u64>()?.encode(env)),
-        DataType::Float32 | DataType::Float64 | DataType::Decimal(_, _) => {
-            Ok(term_from_optional_float(s.max::<f64>()?, env))
+        DataType::Float32 | DataType::Float64 => Ok(term_from_optional_float(s.max::<f64>()?, env)),
+        DataType::Decimal(_, _) => {
+            let decimal = s.decimal()?;
+            match decimal.physical().max() {
+                Some(value) => encoding::encode_decimal(value, decimal.scale(), env),
+                None => Ok(rustler::types::atom::nil().to_term(env)),
+            }
         }
         DataType::Date => Ok(s.max::<i32>()?.map(ExDate::from).encode(env)),
         DataType::Time => Ok(s.max::<i64>()?.map(ExTime::from).encode(env)),
-        DataType::Datetime(unit, _) => Ok(s
+        DataType::Datetime(unit, None) => Ok(s
             .max::<i64>()?
             .map(|v| encode_naive_datetime(v, *unit, env).unwrap())
             .encode(env)),
+        DataType::Datetime(unit, Some(time_zone)) => {
+            let time_zone = encoding::parse_time_zone(time_zone)?;
+            match s.max::<i64>()? {
+                Some(v) => encoding::encode_datetime(v, *unit, time_zone, env),
+                None => Ok(rustler::types::atom::nil().to_term(env)),
+            }
+        }
+        // Enums are ordered by the position of their categories.
+        DataType::Enum(Some(mapping), _) => Ok(s
+            .categorical()?
+            .physical()
+            .max()
+            .map(|id| mapping.get(id))
+            .encode(env)),
         dt => panic!("max/1 not implemented for {dt:?}"),
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_argmax(env: Env, s: ExSeries) -> Result<Term, ExplorerError> {
     Ok(s.arg_max().encode(env))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_argmin(env: Env, s: ExSeries) -> Result<Term, ExplorerError> {
     Ok(s.arg_min().encode(env))
 }
 
+#[nif(schedule = "DirtyCpu")]
+pub fn s_index_of(
+    env: Env,
+    s: ExSeries,
+    value: Option<ExValidValue>,
+) -> Result<Term, ExplorerError> {
+    let value = match value {
+        Some(value) => value.lit_with_matching_precision(s.dtype()),
+        None => Expr::Literal(LiteralValue::Null),
+    };
+    let s1 = apply_lazy_expr(&s, |expr| index_of_expr(expr, value))?;
+
+    Ok(s1.idx()?.get(0).encode(env))
+}
+
+// The position of the first value equal to `value`, or null when there
+// is none. Nil matches nil. Also see the "expressions" module.
+pub fn index_of_expr(expr: Expr, value: Expr) -> Expr {
+    dsl::arg_where(expr.eq_missing(value)).first()
+}
+
 fn is_numeric(dtype: &DataType) -> bool {
     dtype.is_numeric() || matches!(dtype, DataType::Decimal(_, _))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_mean(env: Env, s: ExSeries) -> Result<Term, ExplorerError> {
     if is_numeric(s.dtype()) {
         Ok(term_from_optional_float(s.mean(), env))
+    } else if let DataType::Duration(time_unit) = s.dtype() {
+        // The mean keeps the duration dtype, rounded to the series precision.
+        match s.duration()?.physical().mean() {
+            Some(mean) => encoding::encode_duration(mean.round() as i64, *time_unit, env),
+            None => Ok(rustler::types::atom::nil().to_term(env)),
+        }
     } else {
         panic!("mean/1 not implemented for {:?}", &s.dtype())
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_median(env: Env, s: ExSeries) -> Result<Term, ExplorerError> {
     if is_numeric(s.dtype()) {
         Ok(term_from_optional_float(s.median(), env))
@@ -889,7 +1265,23 @@ pub fn s_median(env: Env, s: ExSeries) -> Result<Term, ExplorerError> {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+// Both sides are cast to floats first, so integer products cannot overflow.
+#[nif(schedule = "DirtyCpu")]
+pub fn s_dot(env: Env, s: ExSeries, other: ExSeries) -> Result<Term, ExplorerError> {
+    if is_numeric(s.dtype()) && is_numeric(other.dtype()) {
+        let left = s.cast(&DataType::Float64)?;
+        let right = other.cast(&DataType::Float64)?;
+        Ok(encoding::term_from_float64(left.dot(&right)?, env))
+    } else {
+        Err(ExplorerError::Other(format!(
+            "dot/2 not implemented for {:?} and {:?}",
+            s.dtype(),
+            other.dtype()
+        )))
+    }
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_mode(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     match mode::mode(&s) {
         Ok(s) => Ok(ExSeries::new(s)),
@@ -897,7 +1289,7 @@ pub fn s_mode(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_product(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     if is_numeric(s.dtype()) {
         let series = s
@@ -916,7 +1308,7 @@ pub fn s_product(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_variance(s: ExSeries, ddof: u8) -> Result<ExSeries, ExplorerError> {
     if is_numeric(s.dtype()) {
         let var_series = s
@@ -935,7 +1327,7 @@ pub fn s_variance(s: ExSeries, ddof: u8) -> Result<ExSeries, ExplorerError> {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_standard_deviation(s: ExSeries, ddof: u8) -> Result<ExSeries, ExplorerError> {
     if is_numeric(s.dtype()) {
         let std_series = s
@@ -954,7 +1346,7 @@ pub fn s_standard_deviation(s: ExSeries, ddof: u8) -> Result<ExSeries, ExplorerE
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_skew(env: Env, s: ExSeries, bias: bool) -> Result<Term, ExplorerError> {
     if is_numeric(s.dtype()) {
         Ok(term_from_optional_float(s.skew(bias)?, env))
@@ -963,7 +1355,7 @@ pub fn s_skew(env: Env, s: ExSeries, bias: bool) -> Result<Term, ExplorerError>
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_correlation(
     env: Env,
     s1: ExSeries,
@@ -989,7 +1381,7 @@ pub fn s_correlation(
     Ok(term_from_optional_float(corr, env))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_covariance(env: Env, s1: ExSeries, s2: ExSeries, ddof: u8) -> Result<Term, ExplorerError> {
     let s1 = s1.clone_inner().cast(&DataType::Float64)?;
     let s2 = s2.clone_inner().cast(&DataType::Float64)?;
@@ -997,14 +1389,14 @@ pub fn s_covariance(env: Env, s1: ExSeries, s2: ExSeries, ddof: u8) -> Result<Te
     Ok(term_from_optional_float(cov, env))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_all(s: ExSeries) -> Result<bool, ExplorerError> {
     let s = s.clone_inner();
 
     Ok(s.bool()?.all())
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_any(s: ExSeries) -> Result<bool, ExplorerError> {
     let s = s.clone_inner();
 
@@ -1018,36 +1410,36 @@ fn term_from_optional_float(option: Option<f64>, env: Env<'_>) -> Term<'_> {
     }
 }
 
-#[rustler::nif]
+#[nif]
 pub fn s_at(env: Env, series: ExSeries, idx: usize) -> Result<Term, ExplorerError> {
     encoding::resource_term_from_value(&series.resource, series.get(idx)?, env)
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_cumulative_sum(series: ExSeries, reverse: bool) -> Result<ExSeries, ExplorerError> {
     let new_series = polars_ops::prelude::cum_sum(&series, reverse)?;
     Ok(ExSeries::new(new_series))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_cumulative_max(series: ExSeries, reverse: bool) -> Result<ExSeries, ExplorerError> {
     let new_series = polars_ops::prelude::cum_max(&series, reverse)?;
     Ok(ExSeries::new(new_series))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_cumulative_min(series: ExSeries, reverse: bool) -> Result<ExSeries, ExplorerError> {
     let new_series = polars_ops::prelude::cum_min(&series, reverse)?;
     Ok(ExSeries::new(new_series))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_cumulative_product(series: ExSeries, reverse: bool) -> Result<ExSeries, ExplorerError> {
     let new_series = polars_ops::prelude::cum_prod(&series, reverse)?;
     Ok(ExSeries::new(new_series))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_quantile<'a>(
     env: Env<'a>,
     s: ExSeries,
@@ -1067,9 +1459,7 @@ pub fn s_quantile<'a>(
         },
         DataType::Datetime(unit, None) => match s.datetime()?.quantile(quantile, strategy)? {
             None => Ok(None::<ExNaiveDateTime>.encode(env)),
-            Some(time) => Ok(encode_naive_datetime(time as i64, *unit, env)
-                .unwrap()
-                .encode(env)),
+            Some(time) => Ok(ExNaiveDateTime::from_timestamp(time as i64, *unit).encode(env)),
         },
         _ => encoding::resource_term_from_value(
             &s.resource,
@@ -1082,13 +1472,14 @@ pub fn s_quantile<'a>(
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_peak_max(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     let ca = match s.dtype() {
         DataType::Int8 => peak_max(s.i8()?),
         DataType::Int16 => peak_max(s.i16()?),
         DataType::Int32 => peak_max(s.i32()?),
         DataType::Int64 => peak_max(s.i64()?),
+        DataType::Int128 => peak_max(s.i128()?),
 
         DataType::UInt8 => peak_max(s.u8()?),
         DataType::UInt16 => peak_max(s.u16()?),
@@ -1109,13 +1500,14 @@ pub fn s_peak_max(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(ca.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_peak_min(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     let ca = match s.dtype() {
         DataType::Int8 => peak_min(s.i8()?),
         DataType::Int16 => peak_min(s.i16()?),
         DataType::Int32 => peak_min(s.i32()?),
         DataType::Int64 => peak_min(s.i64()?),
+        DataType::Int128 => peak_min(s.i128()?),
 
         DataType::UInt8 => peak_min(s.u8()?),
         DataType::UInt16 => peak_min(s.u16()?),
@@ -1136,17 +1528,17 @@ pub fn s_peak_min(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(ca.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_reverse(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(s.reverse()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_n_distinct(s: ExSeries) -> Result<usize, ExplorerError> {
     Ok(s.n_unique()?)
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_cast(s: ExSeries, to_type: ExSeriesDtype) -> Result<ExSeries, ExplorerError> {
     let dtype = DataType::try_from(&to_type)?;
     Ok(ExSeries::new(s.cast(&dtype)?))
@@ -1161,10 +1553,10 @@ pub fn cast_str_to_f64(atom: &str) -> f64 {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_categories(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     match s.dtype() {
-        DataType::Categorical(Some(mapping), _) => {
+        DataType::Categorical(Some(mapping), _) | DataType::Enum(Some(mapping), _) => {
             let size = mapping.len() as u32;
             let categories: Vec<&str> = (0..size).map(|id| mapping.get(id)).collect();
             let series = Series::new("categories".into(), &categories);
@@ -1174,7 +1566,7 @@ pub fn s_categories(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_categorise(s: ExSeries, cat: ExSeries) -> Result<ExSeries, ExplorerError> {
     match cat.dtype() {
         DataType::Categorical(Some(mapping), _) => {
@@ -1204,38 +1596,80 @@ pub fn s_categorise(s: ExSeries, cat: ExSeries) -> Result<ExSeries, ExplorerErro
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+// Lexical ordering compares categories by their string values,
+// while physical ordering compares them by insertion order.
+#[nif(schedule = "DirtyCpu")]
+pub fn s_set_categorical_ordering(
+    s: ExSeries,
+    ordering: ExCategoricalOrdering,
+) -> Result<ExSeries, ExplorerError> {
+    match s.dtype() {
+        DataType::Categorical(Some(mapping), _) => {
+            let categorical_chunks = unsafe {
+                CategoricalChunked::from_cats_and_rev_map_unchecked(
+                    s.categorical()?.physical().clone(),
+                    mapping.clone(),
+                    false,
+                    ordering.into(),
+                )
+            };
+            Ok(ExSeries::new(categorical_chunks.into_series()))
+        }
+        dt => Err(ExplorerError::Other(format!(
+            "cannot set the ordering of a series of dtype {dt:?}"
+        ))),
+    }
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_sample_n(
     series: ExSeries,
     n: usize,
     replace: bool,
     shuffle: bool,
     seed: Option<u64>,
+    weights: Option<ExSeries>,
 ) -> Result<ExSeries, ExplorerError> {
-    let new_s = series.sample_n(n, replace, shuffle, seed)?;
+    let new_s = match weights {
+        Some(weights) => sample::weighted_sample(&series, n, replace, shuffle, seed, &weights)?,
+        None => series.sample_n(n, replace, shuffle, seed)?,
+    };
 
     Ok(ExSeries::new(new_s))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_sample_frac(
     series: ExSeries,
     frac: f64,
     replace: bool,
     shuffle: bool,
     seed: Option<u64>,
+    weights: Option<ExSeries>,
 ) -> Result<ExSeries, ExplorerError> {
-    let new_s = series.sample_frac(frac, replace, shuffle, seed)?;
+    let new_s = match weights {
+        Some(weights) => {
+            let n = (frac * series.len() as f64) as usize;
+            sample::weighted_sample(&series, n, replace, shuffle, seed, &weights)?
+        }
+        None => series.sample_frac(frac, replace, shuffle, seed)?,
+    };
 
     Ok(ExSeries::new(new_s))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn s_shuffle(series: ExSeries, seed: Option<u64>) -> Result<ExSeries, ExplorerError> {
+    Ok(ExSeries::new(series.shuffle(seed)))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_rank(
     series: ExSeries,
     method: ExRankMethod,
     descending: bool,
     seed: Option<u64>,
+    percentage: bool,
 ) -> Result<ExSeries, ExplorerError> {
     let rank_method = parse_rank_method_options(method, descending);
     let rank_data_type = match rank_method.method {
@@ -1249,6 +1683,16 @@ pub fn s_rank(
         .cast(&rank_data_type)?
         .into_series();
 
+    // Percentage ranks are divided by the number of non-null values, so
+    // nils don't lower them. With ties, `:min` and `:dense` ranks never
+    // reach that count, so their highest percentage is below 1.0.
+    if percentage {
+        let count = series.len() - series.null_count();
+        let new_s = new_s.cast(&DataType::Float64)? / count as f64;
+
+        return Ok(ExSeries::new(new_s));
+    }
+
     Ok(ExSeries::new(new_s))
 }
 
@@ -1292,13 +1736,20 @@ pub fn parse_quantile_interpol_options(strategy: &str) -> QuantileMethod {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_coalesce(s1: ExSeries, s2: ExSeries) -> Result<ExSeries, ExplorerError> {
     let coalesced = s1.zip_with(&s1.is_not_null(), &s2)?;
     Ok(ExSeries::new(coalesced))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+// Takes the values from `s` where the mask is true and from `other` elsewhere.
+#[nif(schedule = "DirtyCpu")]
+pub fn s_zip_with(s: ExSeries, mask: ExSeries, other: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let zipped = s.zip_with(mask.bool()?, &other)?;
+    Ok(ExSeries::new(zipped))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_select(
     pred: ExSeries,
     on_true: ExSeries,
@@ -1316,7 +1767,7 @@ pub fn s_select(
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_not(s1: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s2 = s1
         .bool()?
@@ -1327,7 +1778,7 @@ pub fn s_not(s1: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(s2))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_contains(s1: ExSeries, pattern: &str, literal: bool) -> Result<ExSeries, ExplorerError> {
     let chunked_array = if literal {
         s1.str()?.contains_literal(pattern)?
@@ -1337,17 +1788,17 @@ pub fn s_contains(s1: ExSeries, pattern: &str, literal: bool) -> Result<ExSeries
     Ok(ExSeries::new(chunked_array.into()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_upcase(s1: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(s1.str()?.to_uppercase().into()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_downcase(s1: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(s1.str()?.to_lowercase().into()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_replace(
     s1: ExSeries,
     pattern: &str,
@@ -1362,50 +1813,57 @@ pub fn s_replace(
     Ok(ExSeries::new(chunked_array.into()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
-pub fn s_strip(s1: ExSeries, pattern: Option<&str>) -> Result<ExSeries, ExplorerError> {
-    // There are no eager strip functions.
-    let pattern = match pattern {
-        None => String::from(r"^[ \s]+|[ \s]+$"),
-        Some(string) => format!(r#"^[{}]+|[{}]+$"#, &string, &string),
+#[nif(schedule = "DirtyCpu")]
+pub fn s_strip(s: ExSeries, characters: Option<&str>) -> Result<ExSeries, ExplorerError> {
+    let matches_expr = match characters {
+        Some(characters) => characters.lit(),
+        None => Expr::Literal(LiteralValue::Null),
     };
+    let s2 = apply_lazy_expr(&s, |expr| expr.str().strip_chars(matches_expr))?;
+    Ok(ExSeries::new(s2))
+}
 
-    // replace only replaces the leftmost match, so we need to call it twice.
-    Ok(ExSeries::new(
-        s1.str()?
-            .replace(pattern.as_str(), "")?
-            .replace(pattern.as_str(), "")?
-            .into(),
-    ))
+#[nif(schedule = "DirtyCpu")]
+pub fn s_lstrip(s: ExSeries, characters: Option<&str>) -> Result<ExSeries, ExplorerError> {
+    let matches_expr = match characters {
+        Some(characters) => characters.lit(),
+        None => Expr::Literal(LiteralValue::Null),
+    };
+    let s2 = apply_lazy_expr(&s, |expr| expr.str().strip_chars_start(matches_expr))?;
+    Ok(ExSeries::new(s2))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
-pub fn s_lstrip(s1: ExSeries, pattern: Option<&str>) -> Result<ExSeries, ExplorerError> {
-    // There are no eager strip functions.
-    let pattern = match pattern {
-        None => String::from(r"^[ \s]+"),
-        Some(string) => format!(r#"^[{}]+"#, &string),
+#[nif(schedule = "DirtyCpu")]
+pub fn s_rstrip(s: ExSeries, characters: Option<&str>) -> Result<ExSeries, ExplorerError> {
+    let matches_expr = match characters {
+        Some(characters) => characters.lit(),
+        None => Expr::Literal(LiteralValue::Null),
     };
+    let s2 = apply_lazy_expr(&s, |expr| expr.str().strip_chars_end(matches_expr))?;
+    Ok(ExSeries::new(s2))
+}
 
-    Ok(ExSeries::new(
-        s1.str()?.replace(pattern.as_str(), "")?.into(),
-    ))
+#[nif(schedule = "DirtyCpu")]
+pub fn s_strip_prefix(s1: ExSeries, prefix: &str) -> Result<ExSeries, ExplorerError> {
+    let s2 = s1
+        .str()?
+        .strip_prefix(&StringChunked::new("prefix".into(), &[prefix]))
+        .into_series();
+
+    Ok(ExSeries::new(s2))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
-pub fn s_rstrip(s1: ExSeries, pattern: Option<&str>) -> Result<ExSeries, ExplorerError> {
-    // There are no eager strip functions.
-    let pattern = match pattern {
-        None => String::from(r"[ \s]+$"),
-        Some(string) => format!(r#"[{}]+$"#, &string),
-    };
+#[nif(schedule = "DirtyCpu")]
+pub fn s_strip_suffix(s1: ExSeries, suffix: &str) -> Result<ExSeries, ExplorerError> {
+    let s2 = s1
+        .str()?
+        .strip_suffix(&StringChunked::new("suffix".into(), &[suffix]))
+        .into_series();
 
-    Ok(ExSeries::new(
-        s1.str()?.replace(pattern.as_str(), "")?.into(),
-    ))
+    Ok(ExSeries::new(s2))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_substring(
     s: ExSeries,
     offset: i64,
@@ -1427,7 +1885,50 @@ pub fn s_substring(
     Ok(ExSeries::new(s2))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn s_base64_encode(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s2 = apply_lazy_expr(&s, |expr| {
+        expr.cast(DataType::Binary).binary().base64_encode()
+    })?;
+    Ok(ExSeries::new(s2))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_base64_decode(s: ExSeries, strict: bool) -> Result<ExSeries, ExplorerError> {
+    let s2 = apply_lazy_expr(&s, |expr| {
+        expr.cast(DataType::Binary).binary().base64_decode(strict)
+    })?;
+    Ok(ExSeries::new(s2))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_binary_size(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s2 = apply_lazy_expr(&s, |expr| expr.binary().size_bytes())?;
+    Ok(ExSeries::new(s2))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_binary_contains(s: ExSeries, pattern: Binary) -> Result<ExSeries, ExplorerError> {
+    let pattern = pattern.as_slice().lit();
+    let s2 = apply_lazy_expr(&s, |expr| expr.binary().contains_literal(pattern))?;
+    Ok(ExSeries::new(s2))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_binary_starts_with(s: ExSeries, prefix: Binary) -> Result<ExSeries, ExplorerError> {
+    let prefix = prefix.as_slice().lit();
+    let s2 = apply_lazy_expr(&s, |expr| expr.binary().starts_with(prefix))?;
+    Ok(ExSeries::new(s2))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_binary_ends_with(s: ExSeries, suffix: Binary) -> Result<ExSeries, ExplorerError> {
+    let suffix = suffix.as_slice().lit();
+    let s2 = apply_lazy_expr(&s, |expr| expr.binary().ends_with(suffix))?;
+    Ok(ExSeries::new(s2))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_split(s1: ExSeries, by: &str) -> Result<ExSeries, ExplorerError> {
     let s2 = s1
         .str()?
@@ -1437,7 +1938,7 @@ pub fn s_split(s1: ExSeries, by: &str) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(s2))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_split_into(s1: ExSeries, by: &str, names: Vec<String>) -> Result<ExSeries, ExplorerError> {
     let s2 = s1
         .clone_inner()
@@ -1457,83 +1958,297 @@ pub fn s_split_into(s1: ExSeries, by: &str, names: Vec<String>) -> Result<ExSeri
     Ok(ExSeries::new(s2))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_round(s: ExSeries, decimals: u32) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(s.round(decimals)?.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_floor(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(s.floor()?.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_ceil(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(s.ceil()?.into_series()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_abs(s: ExSeries) -> Result<ExSeries, ExplorerError> {
-    Ok(ExSeries::new(abs(&s)?))
+    match s.dtype() {
+        // Durations are taken through their physical representation
+        // so the result keeps the original precision.
+        DataType::Duration(_) => {
+            let s1 = abs(&s.to_physical_repr())?.cast(s.dtype())?;
+            Ok(ExSeries::new(s1))
+        }
+        _ => Ok(ExSeries::new(abs(&s)?)),
+    }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+// Some operations only exist as expressions in Polars, so we
+// compute them by going through a lazy frame with a single column.
+fn apply_lazy_expr<F>(s: &ExSeries, fun: F) -> Result<Series, ExplorerError>
+where
+    F: FnOnce(Expr) -> Expr,
+{
+    let name = s.name().clone();
+    let s2 = s
+        .clone_inner()
+        .into_frame()
+        .lazy()
+        .select([fun(col(name.clone()))])
+        .collect()?
+        .column(&name)?
+        .as_materialized_series()
+        .clone();
+
+    Ok(s2)
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_day_of_week(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s1 = s.weekday()?.into_series();
 
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_day_of_year(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s1 = s.ordinal_day()?.into_series();
 
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_week_of_year(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s1 = s.week()?.into_series();
 
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn s_iso_year(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = s.iso_year()?.into_series();
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_month(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s1 = s.month()?.into_series();
 
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn s_quarter(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = s.quarter()?.into_series();
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_year(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s1 = s.year()?.into_series();
 
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_hour(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s1 = s.hour()?.into_series();
 
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_minute(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s1 = s.minute()?.into_series();
 
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_second(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s1 = s.second()?.into_series();
 
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn s_millisecond(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().millisecond())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_microsecond(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().microsecond())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_nanosecond(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().nanosecond())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_dt_truncate(s: ExSeries, every: &str) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().truncate(every.lit()))?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_dt_round(s: ExSeries, every: &str) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().round(every.lit()))?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_dt_offset_by(s: ExSeries, interval: &str) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().offset_by(interval.lit()))?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_month_start(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().month_start())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_month_end(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().month_end())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_days_in_month(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().month_end().dt().day())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_is_leap_year(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = s.is_leap_year()?.into_series();
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_convert_time_zone(s: ExSeries, time_zone: &str) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().convert_time_zone(time_zone.into()))?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_replace_time_zone(
+    s: ExSeries,
+    time_zone: Option<&str>,
+    ambiguous: &str,
+    non_existent: ExNonExistent,
+) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| {
+        expr.dt().replace_time_zone(
+            time_zone.map(|value| value.into()),
+            ambiguous.lit(),
+            non_existent.into(),
+        )
+    })?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_base_utc_offset(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().base_utc_offset())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_dst_offset(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().dst_offset())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_dt_to_date(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().date())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_dt_to_time(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().time())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_total_days(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().total_days())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_total_hours(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().total_hours())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_total_minutes(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().total_minutes())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_total_seconds(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().total_seconds())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_total_milliseconds(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().total_milliseconds())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_total_microseconds(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().total_microseconds())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_total_nanoseconds(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.dt().total_nanoseconds())?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_strptime(
     s: ExSeries,
     format_string: Option<&str>,
@@ -1558,13 +2273,81 @@ pub fn s_strptime(
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn s_strptime_many(
+    s: ExSeries,
+    formats: Vec<String>,
+    precision: Option<ExTimeUnit>,
+    time_zone: Option<&str>,
+    ambiguous: &str,
+    non_existent: ExNonExistent,
+) -> Result<ExSeries, ExplorerError> {
+    let expr = strptime_many_expr(
+        col(s.name().clone()),
+        formats,
+        precision,
+        time_zone,
+        ambiguous,
+        non_existent,
+    )?;
+    let s1 = apply_lazy_expr(&s, |_| expr)?;
+
+    Ok(ExSeries::new(s1))
+}
+
+// Parses the strings with each format, in order, keeping the first successful
+// parse for each row. Values are parsed as naive datetimes and then localized
+// to the given time zone, if any. Also see the "expressions" module.
+pub fn strptime_many_expr(
+    expr: Expr,
+    formats: Vec<String>,
+    precision: Option<ExTimeUnit>,
+    time_zone: Option<&str>,
+    ambiguous: &str,
+    non_existent: ExNonExistent,
+) -> Result<Expr, ExplorerError> {
+    let timeunit = match precision {
+        None => TimeUnit::Microseconds,
+        Some(precision) => TimeUnit::try_from(&precision)?,
+    };
+
+    let parsed: Vec<Expr> = formats
+        .into_iter()
+        .map(|format| {
+            let options = StrptimeOptions {
+                format: Some(format.into()),
+                strict: false,
+                exact: true,
+                cache: true,
+            };
+
+            expr.clone()
+                .str()
+                .to_datetime(Some(timeunit), None, options, ambiguous.lit())
+        })
+        .collect();
+
+    let expr = dsl::coalesce(&parsed);
+
+    let expr = match time_zone {
+        Some(time_zone) => expr.dt().replace_time_zone(
+            Some(time_zone.into()),
+            ambiguous.lit(),
+            non_existent.into(),
+        ),
+        None => expr,
+    };
+
+    Ok(expr)
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_strftime(s: ExSeries, format_string: &str) -> Result<ExSeries, ExplorerError> {
     let s1 = s.strftime(format_string)?;
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_clip_integer(s: ExSeries, min: i64, max: i64) -> Result<ExSeries, ExplorerError> {
     let s1 = clip(
         &s,
@@ -1575,7 +2358,7 @@ pub fn s_clip_integer(s: ExSeries, min: i64, max: i64) -> Result<ExSeries, Explo
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_clip_float(s: ExSeries, min: f64, max: f64) -> Result<ExSeries, ExplorerError> {
     let s1 = clip(
         &s,
@@ -1586,7 +2369,72 @@ pub fn s_clip_float(s: ExSeries, min: f64, max: f64) -> Result<ExSeries, Explore
     Ok(ExSeries::new(s1))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn s_clip_date(s: ExSeries, min: ExDate, max: ExDate) -> Result<ExSeries, ExplorerError> {
+    let min = Series::new("min_clip".into(), &[i32::from(min)]);
+    let max = Series::new("max_clip".into(), &[i32::from(max)]);
+
+    clip_temporal(&s, min, max)
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_clip_naive_datetime(
+    s: ExSeries,
+    min: ExNaiveDateTime,
+    max: ExNaiveDateTime,
+) -> Result<ExSeries, ExplorerError> {
+    let time_unit = datetime_time_unit(&s)?;
+    let min = Series::new(
+        "min_clip".into(),
+        &[ex_naive_datetime_to_timestamp(min, time_unit)?],
+    );
+    let max = Series::new(
+        "max_clip".into(),
+        &[ex_naive_datetime_to_timestamp(max, time_unit)?],
+    );
+
+    clip_temporal(&s, min, max)
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_clip_datetime(
+    s: ExSeries,
+    min: ExDateTime,
+    max: ExDateTime,
+) -> Result<ExSeries, ExplorerError> {
+    let time_unit = datetime_time_unit(&s)?;
+    let min = Series::new(
+        "min_clip".into(),
+        &[ex_datetime_to_timestamp(min, time_unit)?],
+    );
+    let max = Series::new(
+        "max_clip".into(),
+        &[ex_datetime_to_timestamp(max, time_unit)?],
+    );
+
+    clip_temporal(&s, min, max)
+}
+
+fn datetime_time_unit(s: &Series) -> Result<TimeUnit, ExplorerError> {
+    match s.dtype() {
+        DataType::Datetime(time_unit, _) => Ok(*time_unit),
+        dtype => Err(ExplorerError::Other(format!(
+            "expected a datetime series, got: {dtype}"
+        ))),
+    }
+}
+
+// Bounds are built from their physical values, already in the unit
+// of the series, so casting them to the series dtype is lossless.
+// Zoned datetimes are compared as UTC timestamps.
+fn clip_temporal(s: &Series, min: Series, max: Series) -> Result<ExSeries, ExplorerError> {
+    let dtype = s.dtype();
+    let s1 = clip(s, &min.cast(dtype)?, &max.cast(dtype)?)?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_sin(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     match s.dtype() {
         DataType::Float64 => {
@@ -1608,7 +2456,7 @@ pub fn s_sin(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_cos(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     match s.dtype() {
         DataType::Float64 => {
@@ -1630,7 +2478,7 @@ pub fn s_cos(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_tan(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     match s.dtype() {
         DataType::Float64 => {
@@ -1652,7 +2500,7 @@ pub fn s_tan(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_asin(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     match s.dtype() {
         DataType::Float64 => {
@@ -1674,7 +2522,7 @@ pub fn s_asin(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_acos(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     match s.dtype() {
         DataType::Float64 => {
@@ -1696,7 +2544,7 @@ pub fn s_acos(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_atan(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     match s.dtype() {
         DataType::Float64 => {
@@ -1718,7 +2566,7 @@ pub fn s_atan(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_degrees(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     match s.dtype() {
         DataType::Float64 => {
@@ -1740,7 +2588,7 @@ pub fn s_degrees(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_radians(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     match s.dtype() {
         DataType::Float64 => {
@@ -1762,7 +2610,7 @@ pub fn s_radians(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_join(s1: ExSeries, separator: &str) -> Result<ExSeries, ExplorerError> {
     let s2 = s1
         .list()?
@@ -1772,14 +2620,21 @@ pub fn s_join(s1: ExSeries, separator: &str) -> Result<ExSeries, ExplorerError>
     Ok(ExSeries::new(s2))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_lengths(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     let s2 = s.list()?.lst_lengths().into_series();
 
     Ok(ExSeries::new(s2))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn s_explode(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    let s2 = s.explode()?;
+
+    Ok(ExSeries::new(s2))
+}
+
+#[nif(schedule = "DirtyCpu")]
 fn s_member(
     s: ExSeries,
     value: ExValidValue,
@@ -1801,7 +2656,14 @@ fn s_member(
     Ok(ExSeries::new(s2))
 }
 
-#[rustler::nif]
+#[nif(schedule = "DirtyCpu")]
+pub fn s_list_get(s: ExSeries, index: i64) -> Result<ExSeries, ExplorerError> {
+    let s1 = apply_lazy_expr(&s, |expr| expr.list().get(index.lit(), true))?;
+
+    Ok(ExSeries::new(s1))
+}
+
+#[nif]
 pub fn s_field(s: ExSeries, name: &str) -> Result<ExSeries, ExplorerError> {
     let s2 = s
         .clone_inner()
@@ -1818,7 +2680,7 @@ pub fn s_field(s: ExSeries, name: &str) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(s2))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn s_json_decode(s: ExSeries, ex_dtype: ExSeriesDtype) -> Result<ExSeries, ExplorerError> {
     let dtype = DataType::try_from(&ex_dtype).unwrap();
     let s2 = s
@@ -1837,7 +2699,7 @@ pub fn s_json_decode(s: ExSeries, ex_dtype: ExSeriesDtype) -> Result<ExSeries, E
     Ok(ExSeries::new(s2))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn s_json_path_match(s: ExSeries, json_path: String) -> Result<ExSeries, ExplorerError> {
     let var_series = s
         .clone_inner()
@@ -1852,14 +2714,14 @@ pub fn s_json_path_match(s: ExSeries, json_path: String) -> Result<ExSeries, Exp
     Ok(ExSeries::new(var_series))
 }
 
-#[rustler::nif]
+#[nif]
 pub fn s_row_index(series: ExSeries) -> Result<ExSeries, ExplorerError> {
     let len = u32::try_from(series.len())?;
     let s = Series::new("row_index".into(), 0..len);
     Ok(ExSeries::new(s))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_count_matches(
     s1: ExSeries,
     pattern: &str,
@@ -1873,13 +2735,13 @@ pub fn s_count_matches(
     Ok(ExSeries::new(chunked_array.into()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_re_scan(s1: ExSeries, pattern: &str) -> Result<ExSeries, ExplorerError> {
     let chunked_array = s1.str()?.extract_all(pattern)?;
     Ok(ExSeries::new(chunked_array.into()))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_re_named_captures(s1: ExSeries, pattern: &str) -> Result<ExSeries, ExplorerError> {
     let s2 = s1
         .clone_inner()
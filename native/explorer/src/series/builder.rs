@@ -0,0 +1,78 @@
+use crate::datatypes::ExSeriesDtype;
+use crate::series::from_list::series_from_terms;
+use crate::{ExSeries, ExplorerError};
+
+use explorer_macros::nif;
+use polars::prelude::*;
+use rustler::{Resource, ResourceArc, Term};
+use std::sync::Mutex;
+
+// A builder accumulates chunks into a single series, so Elixir streams
+// can be consumed without materializing the whole list first.
+// The series is taken out of the builder once it is finished.
+pub struct ExSeriesBuilderRef {
+    dtype: DataType,
+    series: Mutex<Option<Series>>,
+}
+
+#[rustler::resource_impl]
+impl Resource for ExSeriesBuilderRef {}
+
+#[nif]
+pub fn s_builder_new(
+    name: &str,
+    dtype: ExSeriesDtype,
+) -> Result<ResourceArc<ExSeriesBuilderRef>, ExplorerError> {
+    let dtype = DataType::try_from(&dtype)?;
+    let series = Series::new_empty(name.into(), &dtype);
+
+    Ok(ExSeriesBuilderRef {
+        dtype,
+        series: Mutex::new(Some(series)),
+    }
+    .into())
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_builder_append_list(
+    builder: ResourceArc<ExSeriesBuilderRef>,
+    chunk: Term,
+) -> Result<(), ExplorerError> {
+    let chunk = series_from_terms(PlSmallStr::EMPTY, chunk, &builder.dtype)?;
+
+    append_chunk(&builder, chunk)
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_builder_append_series(
+    builder: ResourceArc<ExSeriesBuilderRef>,
+    chunk: ExSeries,
+) -> Result<(), ExplorerError> {
+    append_chunk(&builder, chunk.clone_inner())
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_builder_finish(
+    builder: ResourceArc<ExSeriesBuilderRef>,
+) -> Result<ExSeries, ExplorerError> {
+    match builder.series.lock().unwrap().take() {
+        Some(series) => Ok(ExSeries::new(series.rechunk())),
+        None => Err(finished_error()),
+    }
+}
+
+fn append_chunk(builder: &ExSeriesBuilderRef, chunk: Series) -> Result<(), ExplorerError> {
+    let chunk = chunk.strict_cast(&builder.dtype)?;
+
+    match builder.series.lock().unwrap().as_mut() {
+        Some(series) => {
+            series.append(&chunk)?;
+            Ok(())
+        }
+        None => Err(finished_error()),
+    }
+}
+
+fn finished_error() -> ExplorerError {
+    ExplorerError::Other("the builder has already been finished".into())
+}
@@ -1,16 +1,18 @@
 use crate::atoms;
 use crate::datatypes::{
-    ex_datetime_to_timestamp, ex_naive_datetime_to_timestamp, ExDate, ExDateTime, ExDecimal,
-    ExDuration, ExNaiveDateTime, ExSeriesDtype, ExTime, ExTimeUnit,
+    ex_datetime_to_timestamp, ex_naive_datetime_to_timestamp, ExCategoricalOrdering, ExDate,
+    ExDateTime, ExDecimal, ExDuration, ExNaiveDateTime, ExSeriesDtype, ExTime, ExTimeUnit,
 };
 use crate::{ExSeries, ExplorerError};
 
+use explorer_macros::nif;
 use polars::datatypes::DataType;
+use polars::export::arrow::bitmap::Bitmap;
 use polars::prelude::*;
-use rustler::{Binary, Error, ListIterator, NifResult, Term, TermType};
+use rustler::{Atom, Binary, Decoder, Encoder, Error, ListIterator, NifResult, Term, TermType};
 use std::slice;
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_from_list_date(name: &str, val: Term) -> Result<ExSeries, ExplorerError> {
     let iterator = val
         .decode::<ListIterator>()
@@ -46,7 +48,7 @@ pub fn s_from_list_date(name: &str, val: Term) -> Result<ExSeries, ExplorerError
         })
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_from_list_naive_datetime(
     name: &str,
     val: Term,
@@ -89,7 +91,7 @@ pub fn s_from_list_naive_datetime(
         })
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_from_list_datetime(
     name: &str,
     val: Term,
@@ -135,7 +137,7 @@ pub fn s_from_list_datetime(
         })
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_from_list_duration(
     name: &str,
     val: Term,
@@ -176,7 +178,7 @@ pub fn s_from_list_duration(
         })
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_from_list_time(name: &str, val: Term) -> Result<ExSeries, ExplorerError> {
     let iterator = val
         .decode::<ListIterator>()
@@ -212,13 +214,28 @@ pub fn s_from_list_time(name: &str, val: Term) -> Result<ExSeries, ExplorerError
         })
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_from_list_null(name: &str, length: usize) -> ExSeries {
     let s = Series::new_null(name.into(), length);
     ExSeries::new(Series::new(name.into(), s))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn s_from_list_null_typed(
+    name: &str,
+    length: usize,
+    dtype: ExSeriesDtype,
+) -> Result<ExSeries, ExplorerError> {
+    let dtype = DataType::try_from(&dtype)?;
+
+    Ok(ExSeries::new(Series::full_null(
+        name.into(),
+        length,
+        &dtype,
+    )))
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_from_list_decimal(
     name: &str,
     val: Term,
@@ -310,7 +327,7 @@ fn native_float_to_decimal_parts(float: f64) -> Option<(i128, usize)> {
 
 macro_rules! from_list {
     ($name:ident, $type:ty) => {
-        #[rustler::nif(schedule = "DirtyCpu")]
+        #[nif(schedule = "DirtyCpu")]
         pub fn $name(name: &str, val: Term) -> NifResult<ExSeries> {
             val.decode::<Vec<Option<$type>>>()
                 .map(|values| ExSeries::new(Series::new(name.into(), values.as_slice())))
@@ -322,6 +339,7 @@ from_list!(s_from_list_s8, i8);
 from_list!(s_from_list_s16, i16);
 from_list!(s_from_list_s32, i32);
 from_list!(s_from_list_s64, i64);
+from_list!(s_from_list_s128, i128);
 
 from_list!(s_from_list_u8, u8);
 from_list!(s_from_list_u16, u16);
@@ -332,9 +350,13 @@ from_list!(s_from_list_bool, bool);
 from_list!(s_from_list_str, String);
 
 macro_rules! from_list_float {
-    ($name:ident, $type:ty, $module:ident) => {
-        #[rustler::nif(schedule = "DirtyCpu")]
+    ($name:ident, $decode_name:ident, $type:ty, $module:ident) => {
+        #[nif(schedule = "DirtyCpu")]
         pub fn $name(name: &str, val: Term) -> NifResult<ExSeries> {
+            $decode_name(val).map(|values| ExSeries::new(Series::new(name.into(), values)))
+        }
+
+        pub fn $decode_name(val: Term) -> NifResult<Vec<Option<$type>>> {
             let nan = atoms::nan();
             let infinity = atoms::infinity();
             let neg_infinity = atoms::neg_infinity();
@@ -369,17 +391,14 @@ macro_rules! from_list_float {
                     }
                 })
                 .collect::<NifResult<Vec<Option<$type>>>>()
-                .map(|values| {
-                    ExSeries::new(Series::new(name.into(), values))
-                })
         }
     };
 }
 
-from_list_float!(s_from_list_f32, f32, f32);
-from_list_float!(s_from_list_f64, f64, f64);
+from_list_float!(s_from_list_f32, decode_f32_list, f32, f32);
+from_list_float!(s_from_list_f64, decode_f64_list, f64, f64);
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_from_list_binary(name: &str, val: Term) -> NifResult<ExSeries> {
     val.decode::<ListIterator>()?
         .map(|term| {
@@ -390,7 +409,7 @@ pub fn s_from_list_binary(name: &str, val: Term) -> NifResult<ExSeries> {
         .map(|values| ExSeries::new(Series::new(name.into(), values)))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_from_list_categories(name: &str, val: Term) -> NifResult<ExSeries> {
     let decoded = val.decode::<Vec<Option<String>>>()?;
     Ok(ExSeries::new(
@@ -405,7 +424,48 @@ pub fn s_from_list_categories(name: &str, val: Term) -> NifResult<ExSeries> {
     ))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
+pub fn s_from_list_categories_with_ordering(
+    name: &str,
+    val: Term,
+    ordering: ExCategoricalOrdering,
+) -> Result<ExSeries, ExplorerError> {
+    let decoded = val
+        .decode::<Vec<Option<String>>>()
+        .map_err(|err| ExplorerError::Other(format!("expecting list of strings: {err:?}")))?;
+
+    Series::new(name.into(), decoded.as_slice())
+        .cast(&DataType::Categorical(None, ordering.into()))
+        .map(ExSeries::new)
+        .map_err(|error| {
+            ExplorerError::Other(format!(
+                "from_list/2 cannot cast a string series to categories series: {error:?}"
+            ))
+        })
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_from_list_enum(
+    name: &str,
+    val: Term,
+    categories: Vec<String>,
+) -> Result<ExSeries, ExplorerError> {
+    let decoded = val
+        .decode::<Vec<Option<String>>>()
+        .map_err(|err| ExplorerError::Other(format!("expecting list of strings: {err:?}")))?;
+    let dtype = DataType::try_from(&ExSeriesDtype::Enum(categories))?;
+
+    Series::new(name.into(), decoded.as_slice())
+        .strict_cast(&dtype)
+        .map(ExSeries::new)
+        .map_err(|error| {
+            ExplorerError::Other(format!(
+                "from_list/2 cannot cast a string series to an enum series: {error:?}"
+            ))
+        })
+}
+
+#[nif(schedule = "DirtyCpu")]
 pub fn s_from_list_of_series(
     name: &str,
     series_term: Term,
@@ -433,7 +493,7 @@ pub fn s_from_list_of_series(
         .map(ExSeries::new)
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_from_list_of_series_as_structs(
     name: &str,
     series_term: Term,
@@ -458,9 +518,146 @@ pub fn s_from_list_of_series_as_structs(
         .map(ExSeries::new)
 }
 
+#[nif(schedule = "DirtyCpu")]
+pub fn s_from_list_of_maps(
+    name: &str,
+    val: Term,
+    dtype: ExSeriesDtype,
+) -> Result<ExSeries, ExplorerError> {
+    let dtype = DataType::try_from(&dtype)?;
+
+    match dtype {
+        DataType::Struct(fields) => {
+            struct_series_from_maps(name.into(), val, &fields).map(ExSeries::new)
+        }
+        dtype => Err(ExplorerError::Other(format!(
+            "from_list_of_maps/3 expects a struct dtype, got: {dtype:?}"
+        ))),
+    }
+}
+
+// Decodes a list of terms into a series of the given dtype. Structs are
+// decoded from maps, with the values of each field collected in one pass.
+pub fn series_from_terms(
+    name: PlSmallStr,
+    val: Term,
+    dtype: &DataType,
+) -> Result<Series, ExplorerError> {
+    let series = match dtype {
+        DataType::Boolean => Series::new(name, decode_values::<bool>(val)?),
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+            Series::new(name, decode_values::<i64>(val)?)
+        }
+        DataType::Int128 => Series::new(name, decode_values::<i128>(val)?),
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+            Series::new(name, decode_values::<u64>(val)?)
+        }
+        DataType::Float32 | DataType::Float64 => {
+            let values = decode_f64_list(val).map_err(decode_error)?;
+            Series::new(name, values)
+        }
+        DataType::String | DataType::Categorical(_, _) | DataType::Enum(_, _) => {
+            Series::new(name, decode_values::<String>(val)?)
+        }
+        DataType::Binary => {
+            let values = decode_values::<Binary>(val)?;
+            let slices: Vec<Option<&[u8]>> = values
+                .iter()
+                .map(|value| value.as_ref().map(|binary| binary.as_slice()))
+                .collect();
+            Series::new(name, slices)
+        }
+        DataType::Struct(fields) => struct_series_from_maps(name, val, fields)?,
+        dtype => {
+            return Err(ExplorerError::Other(format!(
+                "cannot decode a list of terms into a series of dtype {dtype:?}"
+            )))
+        }
+    };
+
+    Ok(series.strict_cast(dtype)?)
+}
+
+fn struct_series_from_maps(
+    name: PlSmallStr,
+    val: Term,
+    fields: &[Field],
+) -> Result<Series, ExplorerError> {
+    let env = val.get_env();
+    let nil = rustler::types::atom::nil().to_term(env);
+
+    // Fields can be given either with string or with atom keys.
+    let keys: Vec<(Term, Option<Term>)> = fields
+        .iter()
+        .map(|field| {
+            let atom_key = Atom::try_from_bytes(env, field.name.as_bytes())
+                .ok()
+                .flatten()
+                .map(|atom| atom.to_term(env));
+            (field.name.as_str().encode(env), atom_key)
+        })
+        .collect();
+
+    let mut columns: Vec<Vec<Term>> = vec![Vec::new(); fields.len()];
+    let mut validity: Vec<bool> = Vec::new();
+
+    for item in val.decode::<ListIterator>().map_err(decode_error)? {
+        let is_map = match item.get_type() {
+            TermType::Map => true,
+            _ if item == nil => false,
+            term_type => {
+                return Err(ExplorerError::Other(format!(
+                    "from_list_of_maps/3 expects maps or nil, got: {term_type:?}"
+                )))
+            }
+        };
+
+        for ((key, atom_key), column) in keys.iter().zip(columns.iter_mut()) {
+            let value = match is_map {
+                true => item
+                    .map_get(*key)
+                    .ok()
+                    .or_else(|| atom_key.and_then(|atom_key| item.map_get(atom_key).ok()))
+                    .unwrap_or(nil),
+                false => nil,
+            };
+
+            column.push(value);
+        }
+
+        validity.push(is_map);
+    }
+
+    let length = validity.len();
+    let field_series = fields
+        .iter()
+        .zip(columns)
+        .map(|(field, column)| {
+            series_from_terms(field.name.clone(), column.encode(env), &field.dtype)
+        })
+        .collect::<Result<Vec<Series>, ExplorerError>>()?;
+
+    let ca = StructChunked::from_series(name, length, field_series.iter())?;
+    let ca = if validity.iter().all(|valid| *valid) {
+        ca
+    } else {
+        ca.with_outer_validity(Some(validity.into_iter().collect::<Bitmap>()))
+    };
+
+    Ok(ca.into_series())
+}
+
+fn decode_values<'a, T: Decoder<'a>>(val: Term<'a>) -> Result<Vec<Option<T>>, ExplorerError> {
+    val.decode::<Vec<Option<T>>>().map_err(decode_error)
+}
+
+fn decode_error(error: Error) -> ExplorerError {
+    ExplorerError::Other(format!("cannot decode list of terms: {error:?}"))
+}
+
 macro_rules! from_binary {
     ($name:ident, $type:ty, $bytes:expr) => {
-        #[rustler::nif(schedule = "DirtyCpu")]
+        #[nif(schedule = "DirtyCpu")]
         pub fn $name(name: &str, val: Binary) -> ExSeries {
             let slice = val.as_slice();
             let transmuted = unsafe {
@@ -478,8 +675,163 @@ from_binary!(s_from_binary_s8, i8, 1);
 from_binary!(s_from_binary_s16, i16, 2);
 from_binary!(s_from_binary_s32, i32, 4);
 from_binary!(s_from_binary_s64, i64, 8);
+from_binary!(s_from_binary_s128, i128, 16);
 
 from_binary!(s_from_binary_u8, u8, 1);
 from_binary!(s_from_binary_u16, u16, 2);
 from_binary!(s_from_binary_u32, u32, 4);
 from_binary!(s_from_binary_u64, u64, 8);
+
+// Temporal dtypes are read from their physical representation and then cast.
+// Binaries have no alignment guarantee, so each value is decoded from its
+// bytes instead of reinterpreting the binary in place.
+fn temporal_from_binary<T, const N: usize>(
+    name: &str,
+    val: Binary,
+    dtype: DataType,
+    from_bytes: fn([u8; N]) -> T,
+) -> Result<ExSeries, ExplorerError>
+where
+    Series: NamedFrom<Vec<T>, [T]>,
+{
+    let bytes = val.as_slice();
+
+    if bytes.len() % N != 0 {
+        return Err(ExplorerError::Other(format!(
+            "expected the binary size to be a multiple of {N} bytes, got: {}",
+            bytes.len()
+        )));
+    }
+
+    let values: Vec<T> = bytes
+        .chunks_exact(N)
+        .map(|chunk| from_bytes(chunk.try_into().unwrap()))
+        .collect();
+
+    Ok(ExSeries::new(
+        Series::new(name.into(), values).cast(&dtype)?,
+    ))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_from_binary_date(name: &str, val: Binary) -> Result<ExSeries, ExplorerError> {
+    temporal_from_binary(name, val, DataType::Date, i32::from_ne_bytes)
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_from_binary_time(name: &str, val: Binary) -> Result<ExSeries, ExplorerError> {
+    temporal_from_binary(name, val, DataType::Time, i64::from_ne_bytes)
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_from_binary_naive_datetime(
+    name: &str,
+    val: Binary,
+    precision: ExTimeUnit,
+) -> Result<ExSeries, ExplorerError> {
+    let dtype = DataType::Datetime(TimeUnit::try_from(&precision)?, None);
+    temporal_from_binary(name, val, dtype, i64::from_ne_bytes)
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_from_binary_datetime(
+    name: &str,
+    val: Binary,
+    precision: ExTimeUnit,
+    time_zone: &str,
+) -> Result<ExSeries, ExplorerError> {
+    let dtype = DataType::Datetime(TimeUnit::try_from(&precision)?, Some(time_zone.into()));
+    temporal_from_binary(name, val, dtype, i64::from_ne_bytes)
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_from_binary_duration(
+    name: &str,
+    val: Binary,
+    precision: ExTimeUnit,
+) -> Result<ExSeries, ExplorerError> {
+    let dtype = DataType::Duration(TimeUnit::try_from(&precision)?);
+    temporal_from_binary(name, val, dtype, i64::from_ne_bytes)
+}
+
+// Reads `size` bytes at `pointer` as values of the given dtype. The bytes
+// must lie within the values of `owner`, a series returned by
+// `s_to_pointer`, which keeps them alive for the duration of the call. The
+// values are copied once, so the new series does not depend on `owner`.
+#[nif(schedule = "DirtyCpu")]
+pub fn s_from_pointer(
+    name: &str,
+    owner: ExSeries,
+    pointer: u64,
+    size: usize,
+    dtype: ExSeriesDtype,
+) -> Result<ExSeries, ExplorerError> {
+    let dtype = DataType::try_from(&dtype)?;
+    let unsupported = || {
+        ExplorerError::Other(format!(
+            "cannot read series of dtype {dtype} from a pointer"
+        ))
+    };
+
+    // Their physical values cannot be cast back without extra metadata.
+    if dtype.is_categorical() || dtype.is_enum() || dtype.is_decimal() {
+        return Err(unsupported());
+    }
+
+    ensure_owned(&owner, pointer, size)?;
+
+    let series = match dtype.to_physical() {
+        DataType::Int8 => series_from_pointer::<i8>(name, pointer, size),
+        DataType::Int16 => series_from_pointer::<i16>(name, pointer, size),
+        DataType::Int32 => series_from_pointer::<i32>(name, pointer, size),
+        DataType::Int64 => series_from_pointer::<i64>(name, pointer, size),
+        DataType::Int128 => series_from_pointer::<i128>(name, pointer, size),
+        DataType::UInt8 => series_from_pointer::<u8>(name, pointer, size),
+        DataType::UInt16 => series_from_pointer::<u16>(name, pointer, size),
+        DataType::UInt32 => series_from_pointer::<u32>(name, pointer, size),
+        DataType::UInt64 => series_from_pointer::<u64>(name, pointer, size),
+        DataType::Float32 => series_from_pointer::<f32>(name, pointer, size),
+        DataType::Float64 => series_from_pointer::<f64>(name, pointer, size),
+        _ => Err(unsupported()),
+    }?;
+
+    Ok(ExSeries::new(series.cast(&dtype)?))
+}
+
+// Only memory owned by a series can be read, so an arbitrary integer can
+// never be dereferenced.
+fn ensure_owned(owner: &Series, pointer: u64, size: usize) -> Result<(), ExplorerError> {
+    let (start, len) = match super::values_buffer(owner) {
+        Some(buffer) if owner.n_chunks() == 1 => buffer,
+        _ => {
+            return Err(ExplorerError::Other(
+                "expected the owner to be a series returned by to_pointer".into(),
+            ))
+        }
+    };
+
+    match pointer.checked_add(size as u64) {
+        Some(end) if pointer >= start && end <= start + len as u64 => Ok(()),
+        _ => Err(ExplorerError::Other(
+            "expected the pointer and size to be within the values of the owner".into(),
+        )),
+    }
+}
+
+fn series_from_pointer<T>(name: &str, pointer: u64, size: usize) -> Result<Series, ExplorerError>
+where
+    Series: for<'a> NamedFrom<&'a [T], [T]>,
+{
+    let width = std::mem::size_of::<T>();
+
+    if pointer as usize % std::mem::align_of::<T>() != 0 || size % width != 0 {
+        return Err(ExplorerError::Other(format!(
+            "expected a pointer aligned to {width} bytes and a size multiple of {width}"
+        )));
+    }
+
+    // The bytes are owned by a series that is alive for the whole call.
+    let values = unsafe { slice::from_raw_parts(pointer as *const T, size / width) };
+
+    Ok(Series::new(name.into(), values))
+}
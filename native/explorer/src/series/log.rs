@@ -1,10 +1,11 @@
 use crate::atoms;
 use crate::{ExSeries, ExplorerError};
+use explorer_macros::nif;
 use polars::prelude::*;
 use rustler::{Term, TermType};
 use std::f64::consts::E;
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_log(s: ExSeries, base: Term) -> Result<ExSeries, ExplorerError> {
     let nan = atoms::nan();
     let infinity = atoms::infinity();
@@ -34,12 +35,31 @@ pub fn s_log(s: ExSeries, base: Term) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(s))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_log_natural(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(s.log(E)))
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
+#[nif(schedule = "DirtyCpu")]
 pub fn s_exp(s: ExSeries) -> Result<ExSeries, ExplorerError> {
     Ok(ExSeries::new(s.exp()))
 }
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_log1p(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    Ok(ExSeries::new(s.log1p()))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_expm1(s: ExSeries) -> Result<ExSeries, ExplorerError> {
+    Ok(ExSeries::new(expm1(&s)?))
+}
+
+// Polars has no `expm1`, so we apply `f64::exp_m1` ourselves.
+// Computing `exp(x) - 1` directly loses precision for values near zero.
+pub fn expm1(s: &Series) -> PolarsResult<Series> {
+    let s = s.cast(&DataType::Float64)?;
+    let ca = s.f64()?.apply_values(f64::exp_m1);
+
+    Ok(ca.into_series())
+}
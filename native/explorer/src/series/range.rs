@@ -0,0 +1,53 @@
+use crate::datatypes::{ExClosedWindow, ExDate, ExNaiveDateTime, ExTimeUnit};
+use crate::{ExSeries, ExplorerError};
+
+use explorer_macros::nif;
+use polars::lazy::dsl;
+use polars::prelude::*;
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_date_range(
+    name: &str,
+    start: ExDate,
+    stop: ExDate,
+    interval: &str,
+    closed: ExClosedWindow,
+) -> Result<ExSeries, ExplorerError> {
+    let interval = Duration::try_parse(interval)?;
+    let range = dsl::date_range(start.lit(), stop.lit(), interval, closed.into());
+
+    series_from_range_expr(name, range)
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_datetime_range(
+    name: &str,
+    start: ExNaiveDateTime,
+    stop: ExNaiveDateTime,
+    interval: &str,
+    closed: ExClosedWindow,
+    precision: ExTimeUnit,
+    time_zone: Option<&str>,
+) -> Result<ExSeries, ExplorerError> {
+    let interval = Duration::try_parse(interval)?;
+    let range = dsl::datetime_range(
+        start.lit(),
+        stop.lit(),
+        interval,
+        closed.into(),
+        Some(TimeUnit::try_from(&precision)?),
+        time_zone.map(|value| value.into()),
+    );
+
+    series_from_range_expr(name, range)
+}
+
+fn series_from_range_expr(name: &str, range: Expr) -> Result<ExSeries, ExplorerError> {
+    let df = DataFrame::empty()
+        .lazy()
+        .select([range.alias(name)])
+        .collect()?;
+    let series = df.column(name)?.as_materialized_series().clone();
+
+    Ok(ExSeries::new(series))
+}
@@ -0,0 +1,162 @@
+use crate::ExplorerError;
+use polars::prelude::*;
+use rand::seq::{index, SliceRandom};
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64;
+
+// Polars only samples uniformly, so weighted and stratified sampling
+// pick the row indices here and then take them from the series or frame.
+
+pub fn new_rng(seed: Option<u64>) -> Pcg64 {
+    Pcg64::seed_from_u64(seed.unwrap_or_else(rand::random))
+}
+
+/// Reads sampling weights as floats. Nils are treated as a zero weight.
+pub fn weights_from_series(weights: &Series) -> Result<Vec<f64>, ExplorerError> {
+    let weights = weights.cast(&DataType::Float64)?;
+    let weights: Vec<f64> = weights
+        .f64()?
+        .into_iter()
+        .map(|weight| weight.unwrap_or(0.0))
+        .collect();
+
+    if weights
+        .iter()
+        .any(|weight| !weight.is_finite() || *weight < 0.0)
+    {
+        return Err(ExplorerError::Other(
+            "sample weights must be finite and non-negative".into(),
+        ));
+    }
+
+    Ok(weights)
+}
+
+pub fn weighted_sample(
+    series: &Series,
+    n: usize,
+    replace: bool,
+    shuffle: bool,
+    seed: Option<u64>,
+    weights: &Series,
+) -> Result<Series, ExplorerError> {
+    if series.len() != weights.len() {
+        return Err(ExplorerError::Other(format!(
+            "expected weights to have the same size as the series ({}), got: {}",
+            series.len(),
+            weights.len()
+        )));
+    }
+
+    let weights = weights_from_series(weights)?;
+    let mut rng = new_rng(seed);
+    let indices = sample_indices(series.len(), n, replace, shuffle, Some(&weights), &mut rng)?;
+
+    Ok(series.take(&IdxCa::from_vec("idx".into(), indices))?)
+}
+
+/// Picks `n` indices out of `0..len`, optionally weighted.
+///
+/// Without replacement, weighted sampling uses the Efraimidis-Spirakis
+/// algorithm: every row gets the key `u^(1/w)` and the `n` largest keys win.
+/// Rows with a zero weight are never picked.
+pub fn sample_indices(
+    len: usize,
+    n: usize,
+    replace: bool,
+    shuffle: bool,
+    weights: Option<&[f64]>,
+    rng: &mut Pcg64,
+) -> Result<Vec<IdxSize>, ExplorerError> {
+    let mut indices: Vec<usize> = match (weights, replace) {
+        (None, false) => {
+            check_population(n, len)?;
+            index::sample(rng, len, n).into_vec()
+        }
+        (None, true) => {
+            if n > 0 && len == 0 {
+                return Err(ExplorerError::Other(
+                    "cannot take a sample from an empty population".into(),
+                ));
+            }
+
+            (0..n).map(|_| rng.gen_range(0..len)).collect()
+        }
+        (Some(weights), false) => {
+            let mut keys: Vec<(f64, usize)> = weights
+                .iter()
+                .enumerate()
+                .filter(|(_, weight)| **weight > 0.0)
+                .map(|(i, weight)| (rng.gen::<f64>().powf(1.0 / weight), i))
+                .collect();
+
+            check_population(n, keys.len())?;
+            keys.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+            keys.into_iter().take(n).map(|(_, i)| i).collect()
+        }
+        (Some(weights), true) => {
+            let cumulative: Vec<f64> = weights
+                .iter()
+                .scan(0.0, |acc, weight| {
+                    *acc += weight;
+                    Some(*acc)
+                })
+                .collect();
+            let total = cumulative.last().copied().unwrap_or(0.0);
+
+            if n > 0 && total <= 0.0 {
+                return Err(ExplorerError::Other(
+                    "sample weights must have at least one positive value".into(),
+                ));
+            }
+
+            (0..n)
+                .map(|_| {
+                    let target = rng.gen::<f64>() * total;
+                    cumulative.partition_point(|sum| *sum <= target)
+                })
+                .collect()
+        }
+    };
+
+    if shuffle {
+        indices.shuffle(rng);
+    } else {
+        indices.sort_unstable();
+    }
+
+    Ok(indices.into_iter().map(|i| i as IdxSize).collect())
+}
+
+/// Distributes `n` over groups of the given sizes, proportionally to
+/// their size. Leftovers go to the groups with the largest remainders,
+/// so the sizes always add up to `n`.
+pub fn stratified_sizes(sizes: &[usize], n: usize) -> Vec<usize> {
+    let total: usize = sizes.iter().sum();
+
+    if total == 0 {
+        return vec![0; sizes.len()];
+    }
+
+    let mut allocated: Vec<usize> = sizes.iter().map(|size| n * size / total).collect();
+    let leftover = n.saturating_sub(allocated.iter().sum());
+
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    order.sort_by_key(|i| std::cmp::Reverse((n * sizes[*i]) % total));
+
+    for i in order.into_iter().take(leftover) {
+        allocated[i] += 1;
+    }
+
+    allocated
+}
+
+fn check_population(n: usize, len: usize) -> Result<(), ExplorerError> {
+    if n > len {
+        return Err(ExplorerError::Other(
+            "cannot take a larger sample than the total population when `replace: false`".into(),
+        ));
+    }
+
+    Ok(())
+}
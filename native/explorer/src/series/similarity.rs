@@ -0,0 +1,139 @@
+use crate::{ExSeries, ExplorerError};
+use explorer_macros::nif;
+use polars::prelude::*;
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_levenshtein(s: ExSeries, other: ExSeries) -> Result<ExSeries, ExplorerError> {
+    Ok(ExSeries::new(levenshtein_distance(&s, &other)?))
+}
+
+#[nif(schedule = "DirtyCpu")]
+pub fn s_jaro_winkler(s: ExSeries, other: ExSeries) -> Result<ExSeries, ExplorerError> {
+    Ok(ExSeries::new(jaro_winkler_similarity(&s, &other)?))
+}
+
+/// Computes the Levenshtein distance between each pair of strings.
+/// Either side may have a single element, which is then compared
+/// against every element of the other side.
+pub fn levenshtein_distance(left: &Series, right: &Series) -> PolarsResult<Series> {
+    let values = zip_strings(left.str()?, right.str()?, levenshtein)?;
+    Ok(Series::new(left.name().clone(), values))
+}
+
+/// Computes the Jaro-Winkler similarity between each pair of strings.
+/// The result is between 0.0 (no similarity) and 1.0 (equal strings).
+pub fn jaro_winkler_similarity(left: &Series, right: &Series) -> PolarsResult<Series> {
+    let values = zip_strings(left.str()?, right.str()?, jaro_winkler)?;
+    Ok(Series::new(left.name().clone(), values))
+}
+
+fn zip_strings<T, F>(
+    left: &StringChunked,
+    right: &StringChunked,
+    fun: F,
+) -> PolarsResult<Vec<Option<T>>>
+where
+    F: Fn(&str, &str) -> T,
+{
+    let apply = |a: Option<&str>, b: Option<&str>| match (a, b) {
+        (Some(a), Some(b)) => Some(fun(a, b)),
+        _ => None,
+    };
+
+    match (left.len(), right.len()) {
+        (l, r) if l == r => Ok(left
+            .into_iter()
+            .zip(right.into_iter())
+            .map(|(a, b)| apply(a, b))
+            .collect()),
+        (_, 1) => {
+            let b = right.get(0);
+            Ok(left.into_iter().map(|a| apply(a, b)).collect())
+        }
+        (1, _) => {
+            let a = left.get(0);
+            Ok(right.into_iter().map(|b| apply(a, b)).collect())
+        }
+        (l, r) => Err(polars_err!(
+            ShapeMismatch: "cannot compare strings of series with lengths {} and {}", l, r
+        )),
+    }
+}
+
+fn levenshtein(a: &str, b: &str) -> u32 {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<u32> = (0..=b.len() as u32).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i as u32 + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + u32::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matches = vec![false; a.len()];
+    let mut b_matches = vec![false; b.len()];
+    let mut matches = 0;
+
+    for (i, ca) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+
+        for j in start..end {
+            if !b_matches[j] && b[j] == *ca {
+                a_matches[i] = true;
+                b_matches[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let a_matched = a.iter().zip(&a_matches).filter(|(_, m)| **m);
+    let b_matched = b.iter().zip(&b_matches).filter(|(_, m)| **m);
+    let transpositions = a_matched
+        .zip(b_matched)
+        .filter(|((ca, _), (cb, _))| ca != cb)
+        .count()
+        / 2;
+
+    let matches = matches as f64;
+    let jaro = (matches / a.len() as f64
+        + matches / b.len() as f64
+        + (matches - transpositions as f64) / matches)
+        / 3.0;
+
+    let prefix = a
+        .iter()
+        .zip(b.iter())
+        .take(4)
+        .take_while(|(ca, cb)| ca == cb)
+        .count();
+
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
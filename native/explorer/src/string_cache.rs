@@ -0,0 +1,39 @@
+use explorer_macros::nif;
+use polars::datatypes::string_cache::{self, StringCacheHolder};
+use rustler::{Resource, ResourceArc};
+use std::sync::Mutex;
+
+// Categoricals created while the string cache is active share their
+// categories, which allows comparing and joining columns that come
+// from different data frames.
+pub struct StringCacheRef(Mutex<Option<StringCacheHolder>>);
+
+#[rustler::resource_impl]
+impl Resource for StringCacheRef {}
+
+#[nif]
+pub fn enable_string_cache() {
+    string_cache::enable_string_cache()
+}
+
+#[nif]
+pub fn disable_string_cache() {
+    string_cache::disable_string_cache()
+}
+
+#[nif]
+pub fn using_string_cache() -> bool {
+    string_cache::using_string_cache()
+}
+
+// The cache is kept alive until the returned resource is either
+// released or garbage collected.
+#[nif]
+pub fn hold_string_cache() -> ResourceArc<StringCacheRef> {
+    StringCacheRef(Mutex::new(Some(StringCacheHolder::hold()))).into()
+}
+
+#[nif]
+pub fn release_string_cache(holder: ResourceArc<StringCacheRef>) -> bool {
+    holder.0.lock().unwrap().take().is_some()
+}
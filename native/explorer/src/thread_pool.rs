@@ -0,0 +1,23 @@
+use crate::ExplorerError;
+use explorer_macros::nif;
+use polars_core::POOL;
+
+// Polars runs its parallel work on a single global pool, which is sized
+// from `POLARS_MAX_THREADS` the first time it is used and cannot be
+// resized afterwards. Reading its size also starts it.
+
+#[nif]
+pub fn get_max_threads() -> usize {
+    POOL.current_num_threads()
+}
+
+// Setting the variable from here would race with the threads reading the
+// environment, and would only work if the pool has not started yet.
+#[nif]
+pub fn set_max_threads(_max_threads: usize) -> Result<(), ExplorerError> {
+    Err(ExplorerError::Other(
+        "the Polars thread pool cannot be resized at runtime, \
+         set POLARS_MAX_THREADS before the first call to Polars instead"
+            .into(),
+    ))
+}
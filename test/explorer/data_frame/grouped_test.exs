@@ -1370,4 +1370,57 @@ defmodule Explorer.DataFrame.GroupedTest do
              }
     end
   end
+
+  describe "map_groups/2" do
+    setup do
+      df = DF.new(a: ["x", "y", "x", "z", "y"], b: [1, 2, 3, 4, 5])
+      {:ok, grouped: DF.group_by(df, "a")}
+    end
+
+    test "applies the function to each group in order of appearance", %{grouped: grouped} do
+      df1 = DF.map_groups(grouped, fn df -> DF.mutate(df, c: cumulative_sum(b)) end)
+
+      assert df1.groups == []
+
+      assert DF.to_columns(df1, atom_keys: true) == %{
+               a: ["x", "x", "y", "y", "z"],
+               b: [1, 3, 2, 5, 4],
+               c: [1, 4, 2, 7, 4]
+             }
+    end
+
+    test "concatenates frames of any size", %{grouped: grouped} do
+      df1 = DF.map_groups(grouped, fn df -> DF.filter(df, b > 2) end)
+
+      assert DF.to_columns(df1, atom_keys: true) == %{a: ["x", "y", "z"], b: [3, 5, 4]}
+    end
+
+    test "groups by several columns" do
+      df = DF.new(a: ["x", "x", "x"], b: [1, 2, 1], c: [1.0, 2.0, 3.0])
+      grouped = DF.group_by(df, ["a", "b"])
+      df1 = DF.map_groups(grouped, fn df -> DF.summarise(df, c: sum(c)) end)
+
+      assert DF.to_columns(df1, atom_keys: true) == %{c: [4.0, 2.0]}
+    end
+
+    test "raises when the function does not return a dataframe", %{grouped: grouped} do
+      assert_raise ArgumentError,
+                   "map_groups/2 expects the function to return a dataframe, got: :ok",
+                   fn -> DF.map_groups(grouped, fn _ -> :ok end) end
+    end
+
+    test "raises when the results don't have the same columns", %{grouped: grouped} do
+      assert_raise RuntimeError, fn ->
+        DF.map_groups(grouped, fn df ->
+          if DF.n_rows(df) == 1, do: DF.select(df, "a"), else: df
+        end)
+      end
+    end
+
+    test "raises for ungrouped dataframes" do
+      assert_raise ArgumentError, "map_groups/2 expects a grouped dataframe", fn ->
+        DF.map_groups(DF.new(a: [1]), & &1)
+      end
+    end
+  end
 end
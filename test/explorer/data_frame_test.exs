@@ -12,6 +12,8 @@ defmodule Explorer.DataFrameTest do
   import ExUnit.CaptureIO
   alias Explorer.DataFrame, as: DF
   alias Explorer.Datasets
+  alias Explorer.PolarsBackend.Native
+  alias Explorer.PolarsBackend.Shared, as: PolarsShared
   alias Explorer.Series
 
   setup do
@@ -2935,6 +2937,63 @@ defmodule Explorer.DataFrameTest do
     end
   end
 
+  describe "df_upsert/3" do
+    test "updates matching rows in place and appends the others" do
+      target = DF.new(id: [1, 2, 3], name: ~w(a b c))
+      updates = DF.new(id: [3, 4, 1], name: ~w(C D A))
+      df = native_df(:df_upsert, [target.data, updates.data, ["id"]])
+
+      assert DF.to_columns(df, atom_keys: true) == %{
+               id: [1, 2, 3, 4],
+               name: ["A", "b", "C", "D"]
+             }
+    end
+
+    test "with composite keys" do
+      target = DF.new(x: [1, 1, 2], y: ~w(a b a), z: [10, 20, 30])
+      updates = DF.new(x: [1, 2, 2], y: ~w(b b a), z: [21, 40, 31])
+      df = native_df(:df_upsert, [target.data, updates.data, ["x", "y"]])
+
+      assert DF.to_columns(df, atom_keys: true) == %{
+               x: [1, 1, 2, 2],
+               y: ["a", "b", "a", "b"],
+               z: [10, 21, 31, 40]
+             }
+    end
+
+    test "with the columns of the updates in another order" do
+      target = DF.new(id: [1, 2], name: ~w(a b), value: [1.0, 2.0])
+      updates = DF.new(value: [3.0, 4.0], id: [2, 5], name: ~w(B E))
+      df = native_df(:df_upsert, [target.data, updates.data, ["id"]])
+
+      assert df.names == ["id", "name", "value"]
+
+      assert DF.to_columns(df, atom_keys: true) == %{
+               id: [1, 2, 5],
+               name: ["a", "B", "E"],
+               value: [1.0, 3.0, 4.0]
+             }
+    end
+
+    test "raises when the columns differ" do
+      target = DF.new(id: [1, 2], name: ~w(a b))
+      updates = DF.new(id: [1], other: ["x"])
+
+      assert_raise RuntimeError, ~r"expects both dataframes to have the same columns", fn ->
+        native_df(:df_upsert, [target.data, updates.data, ["id"]])
+      end
+    end
+
+    test "raises when the keys are duplicated within the updates" do
+      target = DF.new(id: [1, 2], name: ~w(a b))
+      updates = DF.new(id: [1, 1], name: ~w(x y))
+
+      assert_raise RuntimeError, ~r"upsert expects the keys to be unique", fn ->
+        native_df(:df_upsert, [target.data, updates.data, ["id"]])
+      end
+    end
+  end
+
   describe "distinct/2" do
     test "with lists", %{df: df} do
       df1 = DF.distinct(df, [:year, :country])
@@ -4046,7 +4105,7 @@ defmodule Explorer.DataFrameTest do
              }
 
       assert DF.to_columns(describe_df, atom_keys: true) == %{
-               date: ["3", "1", nil, nil, nil, nil, nil, nil, nil],
+               date: ["3", "1", nil, nil, "1999-12-31", nil, nil, nil, "2023-01-01"],
                naive_datetime: [
                  "3",
                  "1",
@@ -4064,9 +4123,144 @@ defmodule Explorer.DataFrameTest do
                # null: ["0", "4", nil, nil, nil, nil, nil, nil, nil],
                number: [3.0, 1.0, 2.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0],
                string: ["4", "0", nil, nil, nil, nil, nil, nil, nil],
-               time: ["3", "1", nil, nil, nil, nil, nil, nil, nil]
+               time: [
+                 "3",
+                 "1",
+                 nil,
+                 nil,
+                 "00:02:03.000212",
+                 nil,
+                 nil,
+                 nil,
+                 "00:07:04.000776"
+               ]
+             }
+    end
+  end
+
+  describe "df_glimpse/2" do
+    test "returns the dtype and first values of every column" do
+      df = DF.new(a: [1, 2, 3], b: ["x", nil, "z"])
+
+      assert Native.df_glimpse(df.data, 2) ==
+               {:ok, [{"a", {:s, 64}, [1, 2]}, {"b", :string, ["x", nil]}]}
+    end
+
+    test "returns every value of shorter columns" do
+      df = DF.new(a: [1.5, :nan])
+
+      assert Native.df_glimpse(df.data, 6) == {:ok, [{"a", {:f, 64}, [1.5, :nan]}]}
+    end
+
+    test "backs the inspection of dataframes" do
+      df = DF.new(a: Enum.to_list(1..10), b: ["x" | List.duplicate(nil, 9)])
+
+      assert inspect(df) ==
+               """
+               #Explorer.DataFrame<
+                 Polars[10 x 2]
+                 a s64 [1, 2, 3, 4, 5, ...]
+                 b string ["x", nil, nil, nil, nil, ...]
+               >\
+               """
+    end
+  end
+
+  describe "df_describe/2" do
+    test "formats the min and max of temporal columns like their values" do
+      berlin =
+        Enum.map(
+          [~U[2024-03-01 12:00:00.000000Z], ~U[2024-08-01 12:00:00.000000Z]],
+          &DateTime.shift_zone!(&1, "Europe/Berlin")
+        )
+
+      ms =
+        Series.from_list([~N[2021-01-01 00:00:00], ~N[1999-12-31 00:00:00]],
+          dtype: {:naive_datetime, :millisecond}
+        )
+
+      df =
+        DF.new(
+          ms: ms,
+          utc: [~U[2024-01-01 12:00:00.000000Z], ~U[2023-01-01 12:00:00.000000Z]],
+          berlin: berlin
+        )
+
+      describe_df = DF.describe(df, percentiles: [])
+
+      assert DF.to_columns(describe_df, atom_keys: true) == %{
+               describe: ["count", "nil_count", "mean", "std", "min", "max"],
+               ms: ["2", "0", nil, nil, "1999-12-31 00:00:00.000", "2021-01-01 00:00:00.000"],
+               utc: [
+                 "2",
+                 "0",
+                 nil,
+                 nil,
+                 "2023-01-01 12:00:00.000000Z",
+                 "2024-01-01 12:00:00.000000Z"
+               ],
+               berlin: [
+                 "2",
+                 "0",
+                 nil,
+                 nil,
+                 to_string(Enum.at(berlin, 0)),
+                 to_string(Enum.at(berlin, 1))
+               ]
+             }
+    end
+
+    test "describes lazy frames" do
+      df = DF.new(a: ["d", nil, "f"], b: [1, 2, 3])
+
+      assert DF.to_columns(DF.describe(DF.lazy(df))) == DF.to_columns(DF.describe(df))
+    end
+
+    test "computes the statistics of each column" do
+      df = DF.new(a: ["d", nil, "f"], b: [1, 2, 3], c: [10, 20, 30])
+      df1 = native_df(:df_describe, [df.data, [0.3, 0.8]])
+
+      assert DF.to_columns(df1, atom_keys: true) == %{
+               a: ["2", "1", nil, nil, nil, nil, nil, nil],
+               b: [3.0, 0.0, 2.0, 1.0, 1.0, 2.0, 3.0, 3.0],
+               c: [3.0, 0.0, 20.0, 10.0, 10.0, 20.0, 30.0, 30.0],
+               describe: ["count", "nil_count", "mean", "std", "min", "30%", "80%", "max"]
              }
     end
+
+    test "raises for a dataframe without columns" do
+      df = DF.new(a: [1, 2]) |> DF.discard("a")
+
+      assert_raise RuntimeError, ~r"cannot describe a DataFrame without any columns", fn ->
+        native_df(:df_describe, [df.data, []])
+      end
+    end
+  end
+
+  describe "df_column_stats/1" do
+    test "summarises the numeric columns" do
+      df = DF.new(a: [1, nil, 3], b: [1.5, 2.5, nil], s: ~w(x y z))
+      df1 = native_df(:df_column_stats, [df.data])
+
+      assert df1.dtypes["null_count"] == {:u, 64}
+
+      assert DF.to_columns(df1, atom_keys: true) == %{
+               column: ["a", "b"],
+               min: [1.0, 1.5],
+               max: [3.0, 2.5],
+               mean: [2.0, 2.0],
+               sum: [4.0, 4.0],
+               null_count: [1, 1]
+             }
+    end
+
+    test "returns no rows without numeric columns" do
+      df = DF.new(s: ~w(x y z))
+      df1 = native_df(:df_column_stats, [df.data])
+
+      assert df1.names == ["column", "min", "max", "mean", "sum", "null_count"]
+      assert DF.n_rows(df1) == 0
+    end
   end
 
   describe "frequencies/1" do
@@ -4217,6 +4411,150 @@ defmodule Explorer.DataFrameTest do
     end
   end
 
+  describe "df_partition_by/4" do
+    setup do
+      [df: DF.new(a: ["x", "y", "x", nil], b: [1, 1, 2, 3], c: [1.0, 2.0, 3.0, 4.0])]
+    end
+
+    test "returns the groups in order of appearance", %{df: df} do
+      {:ok, dfs} = Native.df_partition_by(df.data, ["a"], true, false)
+
+      assert Enum.map(dfs, &DF.to_columns(PolarsShared.create_dataframe!(&1), atom_keys: true)) ==
+               [
+                 %{a: ["x", "x"], b: [1, 2], c: [1.0, 3.0]},
+                 %{a: ["y"], b: [1], c: [2.0]},
+                 %{a: [nil], b: [3], c: [4.0]}
+               ]
+    end
+
+    test "returns a map keyed by the group value", %{df: df} do
+      {:ok, dfs} = Native.df_partition_by(df.data, ["a"], false, true)
+      dfs = Map.new(dfs, fn {key, df} -> {key, PolarsShared.create_dataframe!(df)} end)
+
+      assert Enum.sort(Map.keys(dfs)) == Enum.sort([nil, "x", "y"])
+      assert DF.names(dfs["x"]) == ["b", "c"]
+      assert DF.to_columns(dfs["x"], atom_keys: true) == %{b: [1, 2], c: [1.0, 3.0]}
+      assert DF.to_columns(dfs[nil], atom_keys: true) == %{b: [3], c: [4.0]}
+    end
+
+    test "returns a map keyed by tuples for several columns", %{df: df} do
+      {:ok, dfs} = Native.df_partition_by(df.data, ["a", "b"], true, true)
+
+      assert Enum.sort(Map.keys(dfs)) == Enum.sort([{"x", 1}, {"y", 1}, {"x", 2}, {nil, 3}])
+
+      assert DF.to_columns(PolarsShared.create_dataframe!(dfs[{"x", 2}]), atom_keys: true) ==
+               %{a: ["x"], b: [2], c: [3.0]}
+    end
+
+    test "returns an error for unknown columns", %{df: df} do
+      assert {:error, {:column_not_found, _, %{column: "z"}}} =
+               Native.df_partition_by(df.data, ["z"], true, true)
+    end
+  end
+
+  describe "df_sample_n/8 and df_sample_frac/8" do
+    setup do
+      df =
+        DF.new(
+          group: ~w(a a a a a a b b b),
+          numbers: [1, 2, 3, 4, 5, 6, 7, 8, 9],
+          weights: [0, 0, 1, 0, 2, 0, 0, 3, 0]
+        )
+
+      {:ok, df: df}
+    end
+
+    test "never picks rows with a zero weight", %{df: df} do
+      for seed <- 1..10 do
+        df1 = native_sample_n(df, 3, seed: seed, weights: "weights")
+        assert df1 |> DF.pull("numbers") |> Series.to_list() == [3, 5, 8]
+      end
+    end
+
+    test "only repeats rows with a positive weight when sampling with replacement", %{df: df} do
+      df1 = native_sample_n(df, 20, replace: true, weights: "weights")
+      numbers = df1 |> DF.pull("numbers") |> Series.to_list()
+
+      assert length(numbers) == 20
+      assert numbers |> Enum.uniq() |> Enum.sort() == [3, 5, 8]
+    end
+
+    test "samples weighted rows within each group", %{df: df} do
+      df1 = native_sample_n(df, 1, groups: ["group"], weights: "weights")
+
+      assert df1 |> DF.pull("group") |> Series.to_list() == ["a", "b"]
+      assert df1 |> DF.pull("numbers") |> Series.to_list() |> List.last() == 8
+    end
+
+    test "distributes a stratified sample in proportion to the groups", %{df: df} do
+      df1 = native_sample_n(df, 3, groups: ["group"], stratified: true)
+      assert df1 |> DF.pull("group") |> Series.to_list() == ["a", "a", "b"]
+
+      # 4 rows are 2.67 and 1.33 rows per group, so the leftover row goes
+      # to the group with the largest remainder.
+      df2 = native_sample_n(df, 4, groups: ["group"], stratified: true)
+      assert df2 |> DF.pull("group") |> Series.to_list() == ["a", "a", "a", "b"]
+    end
+
+    test "takes a weighted fraction of each group", %{df: df} do
+      df = DF.put(df, "weights", [1, 1, 1, 1, 0, 0, 1, 1, 1])
+
+      df1 =
+        PolarsShared.apply_dataframe(df, df, :df_sample_frac, [
+          0.5,
+          false,
+          false,
+          100,
+          ["group"],
+          "weights",
+          false
+        ])
+
+      assert df1 |> DF.pull("group") |> Series.to_list() == ["a", "a", "a", "b"]
+      assert df1 |> DF.pull("numbers") |> Series.to_list() |> Enum.all?(&(&1 not in [5, 6]))
+    end
+
+    test "distributes a stratified fraction in proportion to the groups", %{df: df} do
+      # Half of 9 rows is 4 rows, split as 2.67 and 1.33 rows per group,
+      # where taking half of each group separately would only give 3 rows.
+      df1 =
+        PolarsShared.apply_dataframe(df, df, :df_sample_frac, [
+          0.5,
+          false,
+          false,
+          100,
+          ["group"],
+          nil,
+          true
+        ])
+
+      assert df1 |> DF.pull("group") |> Series.to_list() == ["a", "a", "a", "b"]
+    end
+
+    test "is reproducible with a seed", %{df: df} do
+      df = DF.put(df, "weights", [1, 2, 3, 4, 5, 6, 7, 8, 9])
+
+      df1 = native_sample_n(df, 4, shuffle: true, seed: 42, weights: "weights")
+      df2 = native_sample_n(df, 4, shuffle: true, seed: 42, weights: "weights")
+
+      assert DF.to_columns(df1) == DF.to_columns(df2)
+    end
+
+    test "raises when there are fewer positive weights than rows to take", %{df: df} do
+      assert_raise RuntimeError, ~r"cannot take a larger sample than the total population", fn ->
+        native_sample_n(df, 4, weights: "weights")
+      end
+    end
+
+    test "raises for negative weights", %{df: df} do
+      df = DF.put(df, "weights", [1, 1, 1, 1, 1, 1, 1, 1, -1])
+
+      assert_raise RuntimeError, ~r"sample weights must be finite and non-negative", fn ->
+        native_sample_n(df, 2, weights: "weights")
+      end
+    end
+  end
+
   describe "shuffle/2" do
     test "shuffles the dataframe rows" do
       df = DF.new(letters: ~w(a b c d e f g h i j), numbers: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10])
@@ -4630,6 +4968,19 @@ defmodule Explorer.DataFrameTest do
                cats: [1.0, -1.0]
              }
     end
+
+    test "matches the correlation of each pair of series" do
+      df = DF.new(a: [1, 8, 3, 5, 2], b: [4.5, 5.1, 2.2, 9.8, 0.3], c: [7, 7, 1, 3, 2])
+
+      for method <- [:pearson, :spearman] do
+        df1 = DF.correlation(df, method: method)
+
+        for left <- ~w(a b c), {right, i} <- Enum.with_index(~w(a b c)) do
+          expected = Series.correlation(df[left], df[right], method: method)
+          assert_in_delta Series.at(df1[left], i), expected, 1.0e-12
+        end
+      end
+    end
   end
 
   describe "covariance/2" do
@@ -4718,6 +5069,27 @@ defmodule Explorer.DataFrameTest do
                cats: [1.6666666666666667, -1.0]
              }
     end
+
+    test "with ddof" do
+      df = DF.new(dogs: [1, 0, 2, 1], cats: [2, 3, 0, 1])
+      df1 = DF.covariance(df, ddof: 0)
+
+      assert DF.to_columns(df1, atom_keys: true) == %{
+               names: ["dogs", "cats"],
+               dogs: [0.5, -0.75],
+               cats: [-0.75, 1.25]
+             }
+    end
+
+    test "matches the covariance of each pair of series" do
+      df = DF.new(a: [1, 8, 3, 5, 2], b: [4.5, 5.1, 2.2, 9.8, 0.3], c: [7, 7, 1, 3, 2])
+      df1 = DF.covariance(df)
+
+      for left <- ~w(a b c), {right, i} <- Enum.with_index(~w(a b c)) do
+        expected = Series.covariance(df[left], df[right])
+        assert_in_delta Series.at(df1[left], i), expected, 1.0e-12
+      end
+    end
   end
 
   describe "json_decode/2" do
@@ -4896,4 +5268,125 @@ defmodule Explorer.DataFrameTest do
       end
     end
   end
+
+  describe "native errors" do
+    test "are classified by their Polars variant" do
+      df = DF.new(a: [1, 2, 3])
+
+      assert {:error, {:column_not_found, message, metadata}} = Native.df_pull(df.data, "missing")
+      assert message =~ "Polars Error: "
+      assert message =~ "missing"
+      assert metadata == %{column: "missing"}
+    end
+
+    test "tell the mismatched dtypes of a column" do
+      df1 = DF.new(a: [1, 2], b: ["x", "y"])
+      df2 = DF.new(a: ["z"], b: ["w"])
+
+      assert {:error, {:schema_mismatch, message, metadata}} =
+               Native.df_concat_rows([df1.data, df2.data])
+
+      assert message == ~s(Schema Mismatch: expected column "a" to have dtype i64, got: str)
+      assert metadata == %{column: "a", expected: {:s, 64}, actual: :string}
+    end
+
+    test "stack columns of null dtype" do
+      df1 = DF.new(a: [1, 2])
+      df2 = DF.new(a: [nil])
+
+      assert {:ok, df} = Native.df_concat_rows([df1.data, df2.data])
+      assert Native.df_n_rows(df) == {:ok, 3}
+    end
+
+    test "are classified by their explorer variant" do
+      df = DF.new(id: [1, 1])
+      message = "Generic Error: upsert expects the keys to be unique within the updates"
+
+      assert Native.df_upsert(df.data, df.data, ["id"]) == {:error, {:other, message, %{}}}
+    end
+
+    test "keep their message when raised" do
+      df = DF.new(a: [1, 2, 3])
+
+      assert_raise RuntimeError, ~r"Polars Error: .*missing", fn ->
+        PolarsShared.apply(:df_pull, [df.data, "missing"])
+      end
+    end
+  end
+
+  describe "cancel/1" do
+    setup do
+      [ldf: DF.new([a: [1, 2, 3]], lazy: true)]
+    end
+
+    test "computes a query with a token that was not cancelled", %{ldf: ldf} do
+      token = Native.cancel_token_new()
+
+      assert {:ok, df1} = Native.lf_compute(ldf.data, token, nil)
+      assert {:ok, df2} = Native.lf_compute(ldf.data, token, nil)
+      assert Native.df_n_rows(df1) == {:ok, 3}
+      assert Native.df_n_rows(df2) == {:ok, 3}
+    end
+
+    test "cancels the queries started after cancelling", %{ldf: ldf} do
+      token = Native.cancel_token_new()
+      Native.cancel(token)
+
+      assert Native.lf_compute(ldf.data, token, nil) ==
+               {:error, {:cancelled, "Query cancelled", %{}}}
+
+      assert Native.lf_compute(ldf.data, token, nil) ==
+               {:error, {:cancelled, "Query cancelled", %{}}}
+
+      assert {:ok, _} = Native.lf_compute(ldf.data, Native.cancel_token_new(), nil)
+    end
+
+    @tag :tmp_dir
+    test "cancels eager writes", %{ldf: ldf, tmp_dir: tmp_dir} do
+      path = Path.join(tmp_dir, "cancelled.parquet")
+      compression = PolarsShared.parquet_compression(nil, nil)
+      token = Native.cancel_token_new()
+      Native.cancel(token)
+
+      assert Native.lf_to_parquet(ldf.data, path, compression, false, token, nil) ==
+               {:error, {:cancelled, "Query cancelled", %{}}}
+
+      refute File.exists?(path)
+    end
+
+    @tag :tmp_dir
+    test "rejects a token on streaming writes", %{ldf: ldf, tmp_dir: tmp_dir} do
+      path = Path.join(tmp_dir, "streamed.parquet")
+      compression = PolarsShared.parquet_compression(nil, nil)
+      token = Native.cancel_token_new()
+
+      assert {:error, {:other, message, %{}}} =
+               Native.lf_to_parquet(ldf.data, path, compression, true, token, nil)
+
+      assert message =~ "a cancel token cannot be used with streaming writes"
+    end
+
+    test "raises a runtime error with the cancellation" do
+      error = {:cancelled, "Query cancelled", %{}}
+      assert PolarsShared.runtime_error(error) == %RuntimeError{message: "Query cancelled"}
+    end
+  end
+
+  defp native_df(fun, args) do
+    fun
+    |> PolarsShared.apply(args)
+    |> PolarsShared.create_dataframe!()
+  end
+
+  defp native_sample_n(df, n, opts) do
+    PolarsShared.apply_dataframe(df, df, :df_sample_n, [
+      n,
+      Keyword.get(opts, :replace, false),
+      Keyword.get(opts, :shuffle, false),
+      Keyword.get(opts, :seed, 100),
+      Keyword.get(opts, :groups, []),
+      Keyword.get(opts, :weights),
+      Keyword.get(opts, :stratified, false)
+    ])
+  end
 end
@@ -57,7 +57,7 @@ defmodule Explorer.PolarsBackend.DecimalUnstableTest do
 
     df =
       with {:ok, pdf1} <- PolarsBackend.Native.lf_summarise_with(ldf.data, [], [expr]),
-           {:ok, pdf2} <- PolarsBackend.Native.lf_compute(pdf1),
+           {:ok, pdf2} <- PolarsBackend.Native.lf_compute(pdf1, nil, nil),
            do: PolarsBackend.Shared.create_dataframe!(pdf2)
 
     assert Explorer.Series.to_list(df["a"]) == [nil]
@@ -3,6 +3,8 @@ defmodule Explorer.PolarsBackend.ExpressionTest do
 
   alias Explorer.Backend.LazySeries
   alias Explorer.PolarsBackend.Expression
+  alias Explorer.PolarsBackend.Native
+  alias Explorer.PolarsBackend.Shared
 
   describe "to_expr/1" do
     setup do
@@ -98,4 +100,89 @@ defmodule Explorer.PolarsBackend.ExpressionTest do
                """)
     end
   end
+
+  describe "expr_remap/3" do
+    test "replaces the listed values and keeps the others" do
+      df = Explorer.DataFrame.new(a: [1, 2, 3, nil])
+      old = Native.expr_series(Explorer.Series.from_list([1, 2]).data)
+      new = Native.expr_series(Explorer.Series.from_list([10, 20]).data)
+
+      remap = Native.expr_remap(Native.expr_column("a"), old, new)
+
+      assert mutate!(df, [Native.expr_alias(remap, "b")]).b == [10, 20, 3, nil]
+    end
+  end
+
+  describe "expr_remap_strict/5" do
+    setup do
+      old = Native.expr_series(Explorer.Series.from_list([1, 2]).data)
+      new = Native.expr_series(Explorer.Series.from_list(["one", "two"]).data)
+
+      [df: Explorer.DataFrame.new(a: [1, 2, 3]), old: old, new: new]
+    end
+
+    test "replaces unmatched values with the default", %{df: df, old: old, new: new} do
+      default = Native.expr_string("other")
+
+      {:ok, remap} =
+        Native.expr_remap_strict(Native.expr_column("a"), old, new, default, nil)
+
+      assert mutate!(df, [Native.expr_alias(remap, "b")]).b == ["one", "two", "other"]
+    end
+
+    test "casts to the return dtype", %{df: df, old: old} do
+      new = Native.expr_series(Explorer.Series.from_list([10, 20]).data)
+      default = Native.expr_integer(0)
+
+      {:ok, remap} =
+        Native.expr_remap_strict(Native.expr_column("a"), old, new, default, {:s, 32})
+
+      {:ok, ldf} = Native.df_lazy(df.data)
+      {:ok, ldf} = Native.lf_mutate_with(ldf, [Native.expr_alias(remap, "b")])
+
+      assert Native.lf_dtypes(ldf) == {:ok, [{:s, 64}, {:s, 32}]}
+      assert mutate!(df, [Native.expr_alias(remap, "b")]).b == [10, 20, 0]
+    end
+
+    test "raises on unmatched values without a default", %{df: df, old: old, new: new} do
+      {:ok, remap} = Native.expr_remap_strict(Native.expr_column("a"), old, new, nil, nil)
+
+      {:ok, ldf} = Native.df_lazy(df.data)
+      {:ok, ldf} = Native.lf_mutate_with(ldf, [Native.expr_alias(remap, "b")])
+
+      assert {:error, {:invalid_operation, message, %{}}} = Native.lf_compute(ldf, nil, nil)
+      assert message =~ "incomplete mapping specified for `replace_strict`"
+    end
+  end
+
+  describe "expr_list_eval/2" do
+    setup do
+      [df: Explorer.DataFrame.new(a: [[3, 1, 2], [5, 4], []])]
+    end
+
+    test "compares each element", %{df: df} do
+      inner = Native.expr_greater(Native.expr_element(), Native.expr_integer(2))
+      eval = Native.expr_list_eval(Native.expr_column("a"), inner)
+
+      assert mutate!(df, [Native.expr_alias(eval, "b")]).b ==
+               [[true, false, false], [true, true], []]
+    end
+
+    test "ranks within each list", %{df: df} do
+      inner = Native.expr_rank(Native.expr_element(), :ordinal, false, nil)
+      eval = Native.expr_list_eval(Native.expr_column("a"), inner)
+
+      assert mutate!(df, [Native.expr_alias(eval, "b")]).b == [[3, 1, 2], [2, 1], []]
+    end
+  end
+
+  defp mutate!(df, exprs) do
+    {:ok, ldf} = Native.df_lazy(df.data)
+    {:ok, ldf} = Native.lf_mutate_with(ldf, exprs)
+    {:ok, polars_df} = Native.lf_compute(ldf, nil, nil)
+
+    polars_df
+    |> Shared.create_dataframe!()
+    |> Explorer.DataFrame.to_columns(atom_keys: true)
+  end
 end
@@ -0,0 +1,161 @@
+defmodule Explorer.PolarsBackendTest do
+  use ExUnit.Case, async: true
+
+  require Explorer.DataFrame, as: DF
+  alias Explorer.PolarsBackend
+
+  describe "get_max_threads/0" do
+    test "returns the size of the thread pool" do
+      max_threads = PolarsBackend.get_max_threads()
+
+      assert is_integer(max_threads)
+      assert max_threads > 0
+      assert PolarsBackend.get_max_threads() == max_threads
+    end
+  end
+
+  describe "set_max_threads/1" do
+    test "raises as the thread pool cannot be resized" do
+      assert_raise RuntimeError, ~r"cannot be resized at runtime", fn ->
+        PolarsBackend.set_max_threads(2)
+      end
+    end
+
+    test "raises on invalid sizes" do
+      assert_raise FunctionClauseError, fn -> PolarsBackend.set_max_threads(0) end
+    end
+  end
+
+  describe "collect/2" do
+    test "collects a lazy frame with a token that was not cancelled" do
+      ldf = DF.new([a: [1, 2, 3]], lazy: true) |> DF.mutate(b: a * 2)
+      df = PolarsBackend.collect(ldf, PolarsBackend.cancel_token())
+
+      assert %PolarsBackend.DataFrame{} = df.data
+      assert DF.to_columns(df, atom_keys: true) == %{a: [1, 2, 3], b: [2, 4, 6]}
+    end
+
+    test "raises once the token is cancelled" do
+      ldf = DF.new([a: [1, 2, 3]], lazy: true)
+      token = PolarsBackend.cancel_token()
+
+      assert PolarsBackend.cancel(token) == :ok
+
+      assert_raise RuntimeError, "Query cancelled", fn ->
+        PolarsBackend.collect(ldf, token)
+      end
+    end
+  end
+
+  describe "scan schedulers" do
+    @describetag :tmp_dir
+
+    test "scans run on either dirty scheduler", %{tmp_dir: tmp_dir} do
+      path = Path.join(tmp_dir, "scan.parquet")
+      DF.to_parquet!(DF.new(a: [1, 2, 3]), path)
+
+      for scan <- [:lf_from_parquet, :lf_from_parquet_cpu] do
+        assert {:ok, ldf} = apply(PolarsBackend.Native, scan, [path, nil, nil])
+        assert PolarsBackend.Native.lf_names(ldf) == {:ok, ["a"]}
+      end
+    end
+  end
+
+  describe "with_progress/3" do
+    @describetag :tmp_dir
+
+    test "reports the row groups written and read", %{tmp_dir: tmp_dir} do
+      df = DF.new(a: Enum.to_list(1..600_000))
+      path = Path.join(tmp_dir, "progress.parquet")
+      ref = make_ref()
+
+      assert PolarsBackend.with_progress(self(), ref, fn -> DF.to_parquet(df, path) end) == :ok
+      assert [first | _] = written = progress(ref)
+      assert length(written) > 1
+      assert List.last(written) == 600_000
+
+      read = PolarsBackend.with_progress(self(), ref, fn -> DF.from_parquet!(path) end)
+      assert progress(ref) == written
+      assert DF.to_columns(read) == DF.to_columns(df)
+
+      read =
+        PolarsBackend.with_progress(self(), ref, fn ->
+          DF.from_parquet!(path, max_rows: first + 1)
+        end)
+
+      assert progress(ref) == [first, first + 1]
+      assert DF.n_rows(read) == first + 1
+    end
+
+    test "writes the same row groups without progress", %{tmp_dir: tmp_dir} do
+      df = DF.new(a: Enum.to_list(1..600_000))
+      with_progress = Path.join(tmp_dir, "with_progress.parquet")
+      without_progress = Path.join(tmp_dir, "without_progress.parquet")
+      ref = make_ref()
+
+      PolarsBackend.with_progress(self(), ref, fn -> DF.to_parquet!(df, with_progress) end)
+      DF.to_parquet!(df, without_progress)
+      written = progress(ref)
+
+      PolarsBackend.with_progress(self(), ref, fn -> DF.from_parquet!(without_progress) end)
+      assert progress(ref) == written
+    end
+
+    test "reports the rows written to CSV", %{tmp_dir: tmp_dir} do
+      path = Path.join(tmp_dir, "progress.csv")
+      ref = make_ref()
+
+      PolarsBackend.with_progress(self(), ref, fn -> DF.to_csv!(DF.new(a: [1, 2, 3]), path) end)
+      assert progress(ref) == [3]
+      assert File.read!(path) == "a\n1\n2\n3\n"
+
+      PolarsBackend.with_progress(self(), ref, fn -> DF.to_csv!(DF.new(a: []), path) end)
+      assert progress(ref) == [0]
+      assert File.read!(path) == "a\n"
+    end
+
+    test "reports lazy writes without streaming", %{tmp_dir: tmp_dir} do
+      ldf = DF.new([a: [1, 2, 3]], lazy: true)
+      path = Path.join(tmp_dir, "lazy.parquet")
+      ref = make_ref()
+
+      PolarsBackend.with_progress(self(), ref, fn ->
+        DF.to_parquet!(ldf, path, streaming: false)
+      end)
+
+      assert progress(ref) == [3]
+
+      assert {:error, %RuntimeError{message: message}} =
+               PolarsBackend.with_progress(self(), ref, fn -> DF.to_parquet(ldf, path) end)
+
+      assert message =~ "progress cannot be reported on streaming writes"
+    end
+
+    test "reports collected queries once done" do
+      ldf = DF.new([a: [1, 2, 3]], lazy: true) |> DF.filter(a > 1)
+      ref = make_ref()
+
+      df = PolarsBackend.with_progress(self(), ref, fn -> DF.collect(ldf) end)
+      assert DF.n_rows(df) == 2
+      assert progress(ref) == [2]
+    end
+
+    test "stops reporting once done", %{tmp_dir: tmp_dir} do
+      path = Path.join(tmp_dir, "after.csv")
+      ref = make_ref()
+
+      PolarsBackend.with_progress(self(), ref, fn -> :ok end)
+      DF.to_csv!(DF.new(a: [1]), path)
+
+      assert progress(ref) == []
+    end
+  end
+
+  defp progress(ref) do
+    receive do
+      {^ref, {:progress, rows}} -> [rows | progress(ref)]
+    after
+      0 -> []
+    end
+  end
+end
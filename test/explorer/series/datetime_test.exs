@@ -82,6 +82,46 @@ defmodule Explorer.Series.DateTimeTest do
       assert datetimes_out == datetimes_in
     end
 
+    test "zones with daylight saving time and non-hour offsets" do
+      utc = [
+        ~U[2024-01-15T12:00:00.000000Z],
+        ~U[2024-07-15T12:00:00.000000Z],
+        ~U[2024-10-06T15:45:00.000000Z]
+      ]
+
+      for time_zone <- ["Europe/Berlin", "Asia/Kolkata", "Australia/Lord_Howe"] do
+        datetimes_in = Enum.map(utc, &DateTime.shift_zone!(&1, time_zone))
+        series = Series.from_list(datetimes_in)
+
+        assert series.dtype == {:datetime, :microsecond, time_zone}
+        assert Series.to_list(series) == datetimes_in
+      end
+    end
+
+    test "ambiguous wall times keep their offsets" do
+      # Both are 01:30 on the day New York leaves daylight saving time.
+      datetimes_in =
+        [~U[2024-11-03T05:30:00.000000Z], ~U[2024-11-03T06:30:00.000000Z]]
+        |> Enum.map(&DateTime.shift_zone!(&1, "America/New_York"))
+
+      datetimes_out = datetimes_in |> Series.from_list() |> Series.to_list()
+
+      assert datetimes_out == datetimes_in
+      assert Enum.map(datetimes_out, & &1.std_offset) == [3600, 0]
+    end
+
+    test "min and max keep the zone" do
+      datetimes_in =
+        [~U[2024-03-01T12:00:00.000000Z], nil, ~U[2024-08-01T12:00:00.000000Z]]
+        |> Enum.map(&(&1 && DateTime.shift_zone!(&1, "Europe/Berlin")))
+
+      series = Series.from_list(datetimes_in)
+
+      assert Series.min(series) == Enum.at(datetimes_in, 0)
+      assert Series.max(series) == Enum.at(datetimes_in, 2)
+      assert Series.max(series).zone_abbr == "CEST"
+    end
+
     test "can't build a series from datetimes with non-matching timezones" do
       datetimes_in =
         [
@@ -4,6 +4,7 @@ defmodule Explorer.SeriesTest do
   # Note that for the `{:list, _}` and `{:struct, _}` dtypes, we have a separated file for the tests.
 
   alias Explorer.Series
+  alias Explorer.PolarsBackend.Shared, as: PolarsShared
 
   import ExUnit.CaptureLog
 
@@ -232,6 +233,24 @@ defmodule Explorer.SeriesTest do
       assert Series.dtype(s) == :category
     end
 
+    test "with strings as enums" do
+      s = Series.from_list(["high", nil, "low"], dtype: {:enum, ["low", "medium", "high"]})
+      assert Series.to_list(s) === ["high", nil, "low"]
+      assert Series.dtype(s) == {:enum, ["low", "medium", "high"]}
+    end
+
+    test "with strings missing from the enum categories" do
+      assert_raise RuntimeError, ~r"cannot cast a string series to an enum series", fn ->
+        Series.from_list(["low", "urgent"], dtype: {:enum, ["low", "high"]})
+      end
+    end
+
+    test "with an invalid enum dtype" do
+      assert_raise ArgumentError, ~r"unsupported dtype {:enum, \[:low\]}", fn ->
+        Series.from_list(["low"], dtype: {:enum, [:low]})
+      end
+    end
+
     test "with nils series as string series" do
       s = Series.from_list([nil, nil, nil], dtype: :string)
 
@@ -311,6 +330,16 @@ defmodule Explorer.SeriesTest do
              """
     end
 
+    test "with 128-bit integers" do
+      for dtype <- [:s128, {:s, 128}] do
+        s = Series.from_list([-(2 ** 127), -1, 0, 1, 2 ** 127 - 1, nil], dtype: dtype)
+
+        assert s[1] === -1
+        assert Series.to_list(s) === [-(2 ** 127), -1, 0, 1, 2 ** 127 - 1, nil]
+        assert Series.dtype(s) == {:s, 128}
+      end
+    end
+
     test "with 32-bit integers" do
       for dtype <- [:s32, {:s, 32}] do
         s = Series.from_list([-1, 0, 1, 2, 3, nil], dtype: dtype)
@@ -1787,6 +1816,26 @@ defmodule Explorer.SeriesTest do
   end
 
   describe "add/2" do
+    test "adding two s128 series together" do
+      s1 = Series.from_list([2 ** 100, -1, nil], dtype: {:s, 128})
+      s2 = Series.from_list([1, 2 ** 70, 3], dtype: {:s, 128})
+
+      s3 = Series.add(s1, s2)
+
+      assert s3.dtype == {:s, 128}
+      assert Series.to_list(s3) == [2 ** 100 + 1, 2 ** 70 - 1, nil]
+    end
+
+    test "adding an s64 series to an s128 series" do
+      s1 = Series.from_list([2 ** 100, 1], dtype: {:s, 128})
+      s2 = Series.from_list([1, 2])
+
+      s3 = Series.add(s1, s2)
+
+      assert s3.dtype == {:s, 128}
+      assert Series.to_list(s3) == [2 ** 100 + 1, 3]
+    end
+
     test "adding two s64 series together" do
       s1 = Series.from_list([1, 2, 3])
       s2 = Series.from_list([4, 5, 6])
@@ -2157,6 +2206,16 @@ defmodule Explorer.SeriesTest do
   end
 
   describe "multiply/2" do
+    test "multiplying two s128 series together" do
+      s1 = Series.from_list([2 ** 64, -3], dtype: {:s, 128})
+      s2 = Series.from_list([2 ** 32, 2 ** 100], dtype: {:s, 128})
+
+      s3 = Series.multiply(s1, s2)
+
+      assert s3.dtype == {:s, 128}
+      assert Series.to_list(s3) == [2 ** 96, -3 * 2 ** 100]
+    end
+
     test "multiplying two signed integer series together" do
       s1 = Series.from_list([1, 2, 3])
       s2 = Series.from_list([4, 5, 6])
@@ -3849,6 +3908,55 @@ defmodule Explorer.SeriesTest do
     end
   end
 
+  describe "s_sample_n/6 and s_sample_frac/6 with weights" do
+    test "never picks values with a zero weight" do
+      s = Series.from_list(["a", "b", "c", "d"])
+      weights = Series.from_list([0, 3, nil, 1])
+
+      for seed <- 1..10 do
+        sampled = PolarsShared.apply_series(s, :s_sample_n, [2, false, false, seed, weights.data])
+        assert Series.to_list(sampled) == ["b", "d"]
+      end
+    end
+
+    test "only repeats values with a positive weight when sampling with replacement" do
+      s = Series.from_list(["a", "b", "c"])
+      weights = Series.from_list([0.0, 2.5, 0.0])
+
+      sampled = PolarsShared.apply_series(s, :s_sample_n, [4, true, false, 100, weights.data])
+
+      assert Series.to_list(sampled) == ["b", "b", "b", "b"]
+    end
+
+    test "takes a weighted fraction of the values" do
+      s = Series.from_list([1, 2, 3, 4, 5, 6, 7, 8, 9, 10])
+      weights = Series.from_list([1, 1, 1, 1, 1, 0, 0, 0, 0, 0])
+
+      sampled =
+        PolarsShared.apply_series(s, :s_sample_frac, [0.5, false, false, 100, weights.data])
+
+      assert Series.to_list(sampled) == [1, 2, 3, 4, 5]
+    end
+
+    test "raises when the weights have a different size" do
+      s = Series.from_list(["a", "b", "c"])
+      weights = Series.from_list([1, 1])
+
+      assert_raise RuntimeError, ~r"expected weights to have the same size as the series", fn ->
+        PolarsShared.apply_series(s, :s_sample_n, [1, false, false, 100, weights.data])
+      end
+    end
+
+    test "raises for non-finite weights" do
+      s = Series.from_list(["a", "b"])
+      weights = Series.from_list([1.0, :infinity])
+
+      assert_raise RuntimeError, ~r"sample weights must be finite and non-negative", fn ->
+        PolarsShared.apply_series(s, :s_sample_n, [1, false, false, 100, weights.data])
+      end
+    end
+  end
+
   describe "shuffle/2" do
     test "change the order of the elements randomly" do
       s = 0..9 |> Enum.to_list() |> Series.from_list()
@@ -4021,6 +4129,16 @@ defmodule Explorer.SeriesTest do
 
       assert Series.to_list(result) == [nil, :neg_infinity, 1.0, 2.0, 3.0, :infinity, :nan]
     end
+
+    test "sort an enum series by the position of its categories" do
+      dtype = {:enum, ["low", "medium", "high"]}
+      s1 = Series.from_list(["medium", "low", nil, "high"], dtype: dtype)
+
+      assert s1 |> Series.sort() |> Series.to_list() == ["low", "medium", "high", nil]
+
+      assert s1 |> Series.sort(direction: :desc) |> Series.to_list() ==
+               [nil, "high", "medium", "low"]
+    end
   end
 
   describe "argsort/2" do
@@ -4297,6 +4415,31 @@ defmodule Explorer.SeriesTest do
       assert Series.dtype(s1) == :string
     end
 
+    test "integer series to 128-bit integers and back" do
+      s = Series.from_list([-1, 0, nil, 2 ** 63 - 1])
+      s1 = Series.cast(s, {:s, 128})
+
+      assert Series.to_list(s1) == [-1, 0, nil, 2 ** 63 - 1]
+      assert Series.dtype(s1) == {:s, 128}
+
+      s2 = Series.cast(s1, {:s, 64})
+      assert Series.to_list(s2) == [-1, 0, nil, 2 ** 63 - 1]
+      assert Series.dtype(s2) == {:s, 64}
+    end
+
+    test "string series to enum and back" do
+      s = Series.from_list(["b", "a", nil, "b"])
+      s1 = Series.cast(s, {:enum, ["b", "a"]})
+
+      assert Series.to_list(s1) == ["b", "a", nil, "b"]
+      assert Series.dtype(s1) == {:enum, ["b", "a"]}
+      assert s1 |> Series.categories() |> Series.to_list() == ["b", "a"]
+
+      s2 = Series.cast(s1, :string)
+      assert Series.to_list(s2) == ["b", "a", nil, "b"]
+      assert Series.dtype(s2) == :string
+    end
+
     test "integer series to float" do
       s = Series.from_list([1, 2, 3])
       s1 = Series.cast(s, {:f, 64})
@@ -4562,7 +4705,7 @@ defmodule Explorer.SeriesTest do
 
       assert_raise ArgumentError,
                    "Explorer.Series.slice/2 not implemented for dtype :string. " <>
-                     "Valid dtypes are {:s, 8}, {:s, 16}, {:s, 32}, {:s, 64}, {:u, 8}, {:u, 16}, {:u, 32} and {:u, 64}",
+                     "Valid dtypes are {:s, 8}, {:s, 16}, {:s, 32}, {:s, 64}, {:s, 128}, {:u, 8}, {:u, 16}, {:u, 32} and {:u, 64}",
                    fn -> Series.slice(s, Series.from_list(["0", "2"])) end
     end
 
@@ -5153,6 +5296,271 @@ defmodule Explorer.SeriesTest do
         assert Series.sum(s) === 10
       end
     end
+
+    test "returns a native panic as an error" do
+      s = Series.from_list(["a", "b"])
+
+      assert {:error, {:panic, message, %{}}} = Explorer.PolarsBackend.Native.s_sum(s.data)
+      assert message =~ "sum/1 not implemented for String"
+    end
+  end
+
+  describe "s_builder_*" do
+    alias Explorer.PolarsBackend.Native
+
+    test "accumulates lists and series chunks" do
+      {:ok, builder} = Native.s_builder_new("numbers", {:s, 64})
+
+      assert {:ok, _} = Native.s_builder_append_list(builder, [1, 2])
+      assert {:ok, _} = Native.s_builder_append_list(builder, [nil])
+      assert {:ok, _} = Native.s_builder_append_series(builder, Series.from_list([3, 4]).data)
+
+      {:ok, series} = Native.s_builder_finish(builder)
+      series = PolarsShared.create_series(series)
+
+      assert series.name == "numbers"
+      assert series.dtype == {:s, 64}
+      assert Series.to_list(series) == [1, 2, nil, 3, 4]
+    end
+
+    test "finishes an empty builder" do
+      {:ok, builder} = Native.s_builder_new("empty", :string)
+      {:ok, series} = Native.s_builder_finish(builder)
+
+      assert Series.to_list(PolarsShared.create_series(series)) == []
+    end
+
+    test "returns an error once finished" do
+      {:ok, builder} = Native.s_builder_new("numbers", {:s, 64})
+      assert {:ok, _} = Native.s_builder_append_list(builder, [1])
+      assert {:ok, _} = Native.s_builder_finish(builder)
+
+      assert {:error, {:other, message, %{}}} = Native.s_builder_finish(builder)
+      assert message =~ "the builder has already been finished"
+
+      assert {:error, {:other, message, %{}}} = Native.s_builder_append_list(builder, [2])
+      assert message =~ "the builder has already been finished"
+    end
+  end
+
+  describe "s_to_pointer/1 and s_from_pointer/5" do
+    alias Explorer.PolarsBackend.Native
+
+    test "round-trips numeric and temporal series" do
+      for values <- [[1, 2, 3], [1.5, -2.0], [~D[2024-01-01], ~D[2024-02-29]]] do
+        s = Series.from_list(values)
+
+        {:ok, {pointer, size, owner}} = Native.s_to_pointer(s.data)
+        {:ok, copy} = Native.s_from_pointer("copy", owner, pointer, size, s.dtype)
+        copy = PolarsShared.create_series(copy)
+
+        assert copy.name == "copy"
+        assert copy.dtype == s.dtype
+        assert Series.to_list(copy) == values
+      end
+    end
+
+    test "reads part of the values" do
+      s = Series.from_list([1, 2, 3, 4])
+
+      {:ok, {pointer, 32, owner}} = Native.s_to_pointer(s.data)
+      {:ok, copy} = Native.s_from_pointer("copy", owner, pointer + 8, 16, {:s, 64})
+
+      assert Series.to_list(PolarsShared.create_series(copy)) == [2, 3]
+    end
+
+    test "rejects memory outside of the owner" do
+      s = Series.from_list([1, 2, 3])
+      {:ok, {pointer, size, owner}} = Native.s_to_pointer(s.data)
+
+      for {pointer, size} <- [{pointer + 8, size}, {pointer - 8, 8}, {0, 8}] do
+        assert {:error, {:other, message, %{}}} =
+                 Native.s_from_pointer("copy", owner, pointer, size, {:s, 64})
+
+        assert message == "expected the pointer and size to be within the values of the owner"
+      end
+    end
+
+    test "rejects owners not returned by s_to_pointer" do
+      {:ok, {pointer, size, _owner}} = Native.s_to_pointer(Series.from_list([1, 2]).data)
+      strings = Series.from_list(["a", "b"])
+
+      assert {:error, {:other, message, %{}}} =
+               Native.s_from_pointer("copy", strings.data, pointer, size, {:s, 64})
+
+      assert message == "expected the owner to be a series returned by to_pointer"
+    end
+  end
+
+  describe "s_from_list_of_maps/3" do
+    alias Explorer.PolarsBackend.Native
+
+    @dtype {:struct, [{"a", {:s, 64}}, {"b", :string}]}
+
+    test "builds a struct series from maps with string or atom keys" do
+      maps = [%{"a" => 1, "b" => "x"}, %{a: 2, b: "y"}, %{"a" => 3}]
+
+      {:ok, series} = Native.s_from_list_of_maps("structs", maps, @dtype)
+      series = PolarsShared.create_series(series)
+
+      assert series.dtype == @dtype
+
+      assert Series.to_list(series) == [
+               %{"a" => 1, "b" => "x"},
+               %{"a" => 2, "b" => "y"},
+               %{"a" => 3, "b" => nil}
+             ]
+    end
+
+    test "keeps nil rows as nil" do
+      maps = [%{a: 1, b: "x"}, nil, %{a: nil, b: "z"}]
+
+      {:ok, series} = Native.s_from_list_of_maps("structs", maps, @dtype)
+
+      assert Series.to_list(PolarsShared.create_series(series)) == [
+               %{"a" => 1, "b" => "x"},
+               nil,
+               %{"a" => nil, "b" => "z"}
+             ]
+    end
+
+    test "returns an error for values that are not maps" do
+      assert {:error, {:other, message, %{}}} =
+               Native.s_from_list_of_maps("structs", [%{a: 1}, 2], @dtype)
+
+      assert message =~ "from_list_of_maps/3 expects maps or nil"
+    end
+
+    test "returns an error for non-struct dtypes" do
+      assert {:error, {:other, message, %{}}} =
+               Native.s_from_list_of_maps("structs", [%{a: 1}], {:s, 64})
+
+      assert message =~ "from_list_of_maps/3 expects a struct dtype"
+    end
+  end
+
+  describe "s_from_binary_* with temporal dtypes" do
+    alias Explorer.PolarsBackend.Native
+
+    defp from_binary!(result) do
+      {:ok, series} = result
+      PolarsShared.create_series(series)
+    end
+
+    test "dates from days since the epoch" do
+      binary = <<0::signed-native-32, 19_000::signed-native-32, -1::signed-native-32>>
+      series = from_binary!(Native.s_from_binary_date("dates", binary))
+
+      assert series.dtype == :date
+      assert Series.to_list(series) == [~D[1970-01-01], ~D[2022-01-08], ~D[1969-12-31]]
+    end
+
+    test "times from nanoseconds since midnight" do
+      binary = <<3_600_000_000_000::signed-native-64, 1_500_000::signed-native-64>>
+      series = from_binary!(Native.s_from_binary_time("times", binary))
+
+      assert series.dtype == :time
+      assert Series.to_list(series) == [~T[01:00:00.000000], ~T[00:00:00.001500]]
+    end
+
+    test "naive datetimes with the given precision" do
+      binary = <<1_000::signed-native-64, 86_400_000::signed-native-64>>
+      series = from_binary!(Native.s_from_binary_naive_datetime("ndt", binary, :millisecond))
+
+      assert series.dtype == {:naive_datetime, :millisecond}
+      assert Series.to_list(series) == [~N[1970-01-01 00:00:01.000], ~N[1970-01-02 00:00:00.000]]
+    end
+
+    test "datetimes with the given precision and time zone" do
+      binary = <<1_000_000::signed-native-64>>
+
+      series =
+        from_binary!(Native.s_from_binary_datetime("dt", binary, :microsecond, "Etc/UTC"))
+
+      assert series.dtype == {:datetime, :microsecond, "Etc/UTC"}
+      assert Series.to_list(series) == [~U[1970-01-01 00:00:01.000000Z]]
+    end
+
+    test "durations with the given precision" do
+      binary = <<2_000::signed-native-64>>
+      series = from_binary!(Native.s_from_binary_duration("durations", binary, :nanosecond))
+
+      assert series.dtype == {:duration, :nanosecond}
+      assert Series.to_list(series) == [%Explorer.Duration{value: 2_000, precision: :nanosecond}]
+    end
+
+    test "returns an error for binaries with a partial value" do
+      assert {:error, {:other, message, %{}}} =
+               Native.s_from_binary_time("times", <<1, 2, 3, 4, 5, 6, 7, 8, 9>>)
+
+      assert message =~ "expected the binary size to be a multiple of 8 bytes, got: 9"
+    end
+  end
+
+  describe "s_dot/2" do
+    test "computes the dot product as a float" do
+      left = Series.from_list([1, 2, 3])
+      right = Series.from_list([4, 5, 6])
+
+      assert Explorer.PolarsBackend.Native.s_dot(left.data, right.data) == {:ok, 32.0}
+    end
+
+    test "mixes integers and floats and skips nils" do
+      left = Series.from_list([1, nil, 2])
+      right = Series.from_list([0.5, 3.0, 1.5])
+
+      assert Explorer.PolarsBackend.Native.s_dot(left.data, right.data) == {:ok, 3.5}
+    end
+
+    test "returns an error for non-numeric series" do
+      left = Series.from_list(["a", "b"])
+      right = Series.from_list([1, 2])
+
+      assert {:error, {:other, message, %{}}} =
+               Explorer.PolarsBackend.Native.s_dot(left.data, right.data)
+
+      assert message =~ "dot/2 not implemented for String and Int64"
+    end
+  end
+
+  describe "s_scatter/3" do
+    test "sets the values at the given indices" do
+      series = Series.from_list([1, 2, 3, 4])
+      values = Series.from_list([10.0, 30.0])
+
+      scattered = PolarsShared.apply_series(series, :s_scatter, [[0, 2], values.data])
+
+      assert Series.to_list(scattered) == [10, 2, 30, 4]
+    end
+
+    test "broadcasts a single value" do
+      series = Series.from_list(["a", "b", "c"])
+      values = Series.from_list(["z"])
+
+      scattered = PolarsShared.apply_series(series, :s_scatter, [[0, 2], values.data])
+
+      assert Series.to_list(scattered) == ["z", "b", "z"]
+    end
+
+    test "returns an error for an out-of-bounds index" do
+      series = Series.from_list([1, 2, 3])
+      values = Series.from_list([10])
+
+      assert {:error, {:other, message, %{}}} =
+               Explorer.PolarsBackend.Native.s_scatter(series.data, [3], values.data)
+
+      assert message =~ "scatter/3 cannot set index 3 on a series of length 3"
+    end
+
+    test "returns an error when values and indices don't match" do
+      series = Series.from_list([1, 2, 3])
+      values = Series.from_list([10, 20])
+
+      assert {:error, {:other, message, %{}}} =
+               Explorer.PolarsBackend.Native.s_scatter(series.data, [0, 1, 2], values.data)
+
+      assert message =~ "got 2 values for 3 indices"
+    end
   end
 
   describe "product/1" do
@@ -5298,6 +5706,11 @@ defmodule Explorer.SeriesTest do
       s = Series.from_list([-3.1, 1.2, 2.3, nil, -2.4, -12.6, :neg_infinity, 3.9])
       assert Series.min(s) === :neg_infinity
     end
+
+    test "min of an enum series" do
+      s = Series.from_list(["medium", "high", nil], dtype: {:enum, ["low", "medium", "high"]})
+      assert Series.min(s) === "medium"
+    end
   end
 
   describe "max/1" do
@@ -5330,6 +5743,11 @@ defmodule Explorer.SeriesTest do
       s = Series.from_list([-3.1, 1.2, 2.3, nil, -2.4, -12.6, :neg_infinity, 3.9])
       assert Series.max(s) === 3.9
     end
+
+    test "max of an enum series" do
+      s = Series.from_list(["high", "low", nil], dtype: {:enum, ["high", "medium", "low"]})
+      assert Series.max(s) === "low"
+    end
   end
 
   describe "rank/2" do
@@ -5412,6 +5830,19 @@ defmodule Explorer.SeriesTest do
       assert Series.to_list(r) === [3.0, 5.0, 6.0, nil, 4.0, 2.0, 1.0, 7.0]
     end
 
+    test "percentage ranks are relative to the non-nil values" do
+      s = Series.from_list([3, 6, 1, nil, 6])
+
+      r = PolarsShared.apply_series(s, :s_rank, [:average, false, nil, true])
+      assert Series.to_list(r) === [0.5, 0.875, 0.25, nil, 0.875]
+
+      r = PolarsShared.apply_series(s, :s_rank, [:average, true, nil, true])
+      assert Series.to_list(r) === [0.75, 0.375, 1.0, nil, 0.375]
+
+      r = PolarsShared.apply_series(s, :s_rank, [:dense, false, nil, true])
+      assert Series.to_list(r) === [0.5, 0.75, 0.25, nil, 0.75]
+    end
+
     test "invalid rank method" do
       s = Series.from_list([3.5, 3.0, 3.2, 3.1, 3.6, 3.9, 3.4, 3.4, 2.9, 3.1])
 
@@ -5494,7 +5925,7 @@ defmodule Explorer.SeriesTest do
 
       assert_raise ArgumentError,
                    "Explorer.Series.clip/3 not implemented for dtype :string. " <>
-                     "Valid dtypes are {:f, 32}, {:f, 64}, {:s, 8}, {:s, 16}, {:s, 32}, {:s, 64}, {:u, 8}, {:u, 16}, {:u, 32} and {:u, 64}",
+                     "Valid dtypes are {:f, 32}, {:f, 64}, {:s, 8}, {:s, 16}, {:s, 32}, {:s, 64}, {:s, 128}, {:u, 8}, {:u, 16}, {:u, 32} and {:u, 64}",
                    fn -> Series.clip(Series.from_list(["a"]), 1, 10) end
     end
   end
@@ -6153,6 +6584,16 @@ defmodule Explorer.SeriesTest do
       assert Explorer.DataFrame.names(df) == ["values", "bp", "cat"]
     end
 
+    test "cut/3 reports mismatched labels as a shape mismatch" do
+      series = Series.from_list([1, 2, 3])
+      args = [series.data, [2.0], ["x"], nil, nil, false, false]
+
+      assert {:error, {:shape_mismatch, message, %{}}} =
+               Kernel.apply(Explorer.PolarsBackend.Native, :s_cut, args)
+
+      assert message =~ "Polars Error: "
+    end
+
     test "cut/3 with include breaks" do
       series = Series.from_list([1.0, 2.0, 3.0])
       df = Series.cut(series, [1.5, 2.5], include_breaks: true)
@@ -6212,6 +6653,38 @@ defmodule Explorer.SeriesTest do
                values: [-5, -4, -3, -2, -1, 0, 1, 2, 3]
              }
     end
+
+    test "qcut/3 drops duplicated break points" do
+      series = Series.from_list([0, 0, 0, 0, 1, 2])
+      df = Series.qcut(series, [0.1, 0.25, 0.9], allow_duplicates: true)
+
+      assert Series.to_list(df[:category]) == [
+               "(-inf, 0]",
+               "(-inf, 0]",
+               "(-inf, 0]",
+               "(-inf, 0]",
+               "(0, 1.5]",
+               "(1.5, inf]"
+             ]
+    end
+
+    test "qcut/3 with duplicates and custom labels" do
+      series = Series.from_list([0, 0, 0, 0, 1, 2])
+
+      df =
+        Series.qcut(series, [0.1, 0.25, 0.9],
+          allow_duplicates: true,
+          labels: ["low", "mid", "high"]
+        )
+
+      assert Series.to_list(df[:category]) == ["low", "low", "low", "low", "mid", "high"]
+    end
+
+    test "qcut/3 raises on duplicated break points unless allowed" do
+      series = Series.from_list([0, 0, 0, 0, 1, 2])
+
+      assert_raise RuntimeError, fn -> Series.qcut(series, [0.1, 0.25, 0.9]) end
+    end
   end
 
   describe "join/2" do
@@ -6753,6 +7226,99 @@ defmodule Explorer.SeriesTest do
     end
   end
 
+  describe "s_levenshtein/2" do
+    test "counts the edits between each pair of strings" do
+      s1 = Series.from_list(["kitten", "abc", "", "abc", "ab", nil])
+      s2 = Series.from_list(["sitting", "abc", "abc", "", "ba", "abc"])
+
+      result = PolarsShared.apply_series(s1, :s_levenshtein, [s2.data])
+
+      assert result.dtype == {:u, 32}
+      assert Series.to_list(result) == [3, 0, 3, 3, 2, nil]
+    end
+
+    test "counts characters rather than bytes" do
+      s1 = Series.from_list(["café", "ü", "日本語"])
+      s2 = Series.from_list(["cafe", "u", "日本"])
+
+      result = PolarsShared.apply_series(s1, :s_levenshtein, [s2.data])
+
+      assert Series.to_list(result) == [1, 1, 1]
+    end
+
+    test "compares every string against a single one" do
+      s1 = Series.from_list(["cat", "hat", "cart"])
+      s2 = Series.from_list(["cat"])
+
+      assert s1
+             |> PolarsShared.apply_series(:s_levenshtein, [s2.data])
+             |> Series.to_list() == [0, 1, 1]
+
+      assert s2
+             |> PolarsShared.apply_series(:s_levenshtein, [s1.data])
+             |> Series.to_list() == [0, 1, 1]
+    end
+
+    test "raises when the lengths do not match" do
+      s1 = Series.from_list(["a", "b", "c"])
+      s2 = Series.from_list(["a", "b"])
+
+      assert_raise RuntimeError, ~r"cannot compare strings of series with lengths 3 and 2", fn ->
+        PolarsShared.apply_series(s1, :s_levenshtein, [s2.data])
+      end
+    end
+  end
+
+  describe "s_jaro_winkler/2" do
+    test "handles empty and identical strings" do
+      s1 = Series.from_list(["", "abc", "", "abc", nil])
+      s2 = Series.from_list(["", "", "abc", "abc", "abc"])
+
+      result = PolarsShared.apply_series(s1, :s_jaro_winkler, [s2.data])
+
+      assert result.dtype == {:f, 64}
+      assert Series.to_list(result) == [1.0, 0.0, 0.0, 1.0, nil]
+    end
+
+    test "returns zero for strings without matching characters" do
+      s1 = Series.from_list(["abc", "ab"])
+      s2 = Series.from_list(["xyz", "ba"])
+
+      result = PolarsShared.apply_series(s1, :s_jaro_winkler, [s2.data])
+
+      assert Series.to_list(result) == [0.0, 0.0]
+    end
+
+    test "counts transpositions and boosts common prefixes" do
+      s1 = Series.from_list(["MARTHA", "DIXON", "CRATE"])
+      s2 = Series.from_list(["MARHTA", "DICKSONX", "TRACE"])
+
+      [martha, dixon, crate] =
+        s1
+        |> PolarsShared.apply_series(:s_jaro_winkler, [s2.data])
+        |> Series.to_list()
+
+      # The Jaro similarities are 0.9444, 0.7667 and 0.7333. Only the first
+      # two share a prefix, of 3 and 2 characters, which raises their score.
+      assert_in_delta martha, 0.9611, 1.0e-4
+      assert_in_delta dixon, 0.8133, 1.0e-4
+      assert_in_delta crate, 0.7333, 1.0e-4
+    end
+
+    test "compares characters rather than bytes" do
+      s1 = Series.from_list(["café", "日本"])
+      s2 = Series.from_list(["cafe", "日本"])
+
+      [cafe, japan] =
+        s1
+        |> PolarsShared.apply_series(:s_jaro_winkler, [s2.data])
+        |> Series.to_list()
+
+      assert_in_delta cafe, 0.8833, 1.0e-4
+      assert japan == 1.0
+    end
+  end
+
   defp all_close?(a, b, tol \\ 1.0e-8) do
     Series.subtract(a, b)
     |> Series.abs()